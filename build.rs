@@ -0,0 +1,71 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Generates the solution registry used by the `aoc` runner binary.
+///
+/// Every `src/bin/YYYY_DD.rs` file is included as a module of the runner, so the per-day files remain the only
+/// source of the solutions and a new day is registered just by adding its file.
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut names = vec![];
+
+    for entry in fs::read_dir(&bin_dir).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.extension().and_then(|s| s.to_str()) != Some("rs") {
+            continue;
+        }
+
+        if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+            if is_solution_name(name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+
+    names.sort();
+
+    let mut out = String::new();
+
+    for name in &names {
+        let path = bin_dir.join(format!("{name}.rs"));
+        writeln!(out, "#[path = {:?}]", path.display().to_string()).unwrap();
+        writeln!(out, "mod y{name};").unwrap();
+    }
+
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "/// Returns every solution in `src/bin`, ordered by year and day."
+    )
+    .unwrap();
+    writeln!(out, "pub fn solutions() -> Vec<Box<dyn aoc::Solution>> {{").unwrap();
+    writeln!(out, "    vec![").unwrap();
+
+    for name in &names {
+        writeln!(out, "        y{name}::solution(),").unwrap();
+    }
+
+    writeln!(out, "    ]").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    fs::write(Path::new(&out_dir).join("solutions.rs"), out).unwrap();
+}
+
+/// Returns true if [name] has the `YYYY_DD` form used by the solution files.
+fn is_solution_name(name: &str) -> bool {
+    let bytes = name.as_bytes();
+
+    bytes.len() == 7
+        && bytes[4] == b'_'
+        && bytes[..4].iter().all(u8::is_ascii_digit)
+        && bytes[5..].iter().all(u8::is_ascii_digit)
+}
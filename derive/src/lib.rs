@@ -0,0 +1,414 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, LitStr, Member, Result};
+
+/// Derives `FromStr` for a struct or enum from a line template, given by `#[aoc(fmt = "...")]`.
+///
+/// Each `{field}` placeholder in the template names a field, or its index in a tuple struct, and is parsed with the
+/// field's own `FromStr`. The rest of the template must match the line exactly. The template is matched by
+/// `aoc::parse::scan_captures`, so placeholders must be separated by some literal text.
+///
+/// ```ignore
+/// #[derive(AocParse)]
+/// #[aoc(fmt = "{ax},{ay} -> {bx},{by}")]
+/// struct Line {
+///     ax: usize,
+///     ay: usize,
+///     bx: usize,
+///     by: usize,
+/// }
+/// ```
+///
+/// Each variant of an enum has its own template, and the first that matches is used:
+///
+/// ```ignore
+/// #[derive(AocParse)]
+/// enum Command {
+///     #[aoc(fmt = "forward {0}")]
+///     Forward(i32),
+///     #[aoc(fmt = "stop")]
+///     Stop,
+/// }
+/// ```
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn derive_aoc_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let template = template(&input.attrs, input)?;
+            let construct = construct(quote!(Self), &data.fields, &template)?;
+            let count = template.len();
+
+            quote! {
+                #[allow(unused_variables)]
+                let captures = ::aoc::parse::scan_captures(#template, s, #count)?;
+                Ok(#construct)
+            }
+        }
+        Data::Enum(data) => {
+            let mut attempts = vec![];
+            let mut templates = vec![];
+
+            for variant in &data.variants {
+                let template = template(&variant.attrs, variant)?;
+                let ident = &variant.ident;
+                let construct = construct(quote!(Self::#ident), &variant.fields, &template)?;
+                let count = template.len();
+                let prefix = &template.literals[0];
+
+                attempts.push(quote! {
+                    let attempt = || -> ::aoc::Result<Self> {
+                        #[allow(unused_variables)]
+                        let captures = ::aoc::parse::scan_captures(#template, s, #count)?;
+                        Ok(#construct)
+                    };
+
+                    match attempt() {
+                        Ok(value) => return Ok(value),
+                        Err(e) if !#prefix.is_empty() && s.starts_with(#prefix) => error = Some(e),
+                        Err(_) => {}
+                    }
+                });
+
+                templates.push(format!("`{}`", template.text));
+            }
+
+            let expected = format!("expected one of {}", templates.join(", "));
+
+            quote! {
+                let mut error = None;
+                #(#attempts)*
+                Err(error.unwrap_or_else(|| ::aoc::Error::new(#expected).at(s)))
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                input.span(),
+                "AocParse cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::aoc::Error;
+
+            fn from_str(s: &str) -> ::aoc::Result<Self> {
+                #body
+            }
+        }
+    })
+}
+
+/// A line template, split into the literal text around each placeholder.
+struct Template {
+    /// The template as written.
+    text: String,
+    /// The literal text before, between and after the placeholders, one more than there are placeholders.
+    literals: Vec<String>,
+    /// The field named by each placeholder.
+    fields: Vec<String>,
+    span: proc_macro2::Span,
+}
+
+impl Template {
+    fn parse(lit: &LitStr) -> Result<Template> {
+        let text = lit.value();
+        let span = lit.span();
+
+        let mut literals = vec![String::new()];
+        let mut fields = vec![];
+        let mut chars = text.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' => {
+                    let mut field = String::new();
+                    let mut closed = false;
+
+                    for c in chars.by_ref() {
+                        match c {
+                            '}' => {
+                                closed = true;
+                                break;
+                            }
+                            '{' => break,
+                            c => field.push(c),
+                        }
+                    }
+
+                    if !closed {
+                        return Err(Error::new(span, "unmatched `{` in the template"));
+                    }
+
+                    if field.is_empty() || !field.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                        return Err(Error::new(
+                            span,
+                            format!("invalid placeholder `{{{field}}}`"),
+                        ));
+                    }
+
+                    if literals.last().is_some_and(String::is_empty) && !fields.is_empty() {
+                        return Err(Error::new(
+                            span,
+                            "placeholders must be separated by some text",
+                        ));
+                    }
+
+                    fields.push(field);
+                    literals.push(String::new());
+                }
+                '}' => return Err(Error::new(span, "unmatched `}` in the template")),
+                c => literals.last_mut().unwrap().push(c),
+            }
+        }
+
+        Ok(Template {
+            text,
+            literals,
+            fields,
+            span,
+        })
+    }
+
+    /// Returns the number of placeholders.
+    fn len(&self) -> usize {
+        self.fields.len()
+    }
+}
+
+impl quote::ToTokens for Template {
+    /// Writes the template in the form read by `scan_captures`, with each placeholder as `{}`.
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let text = self.literals.join("{}");
+        tokens.extend(quote!(#text));
+    }
+}
+
+/// Returns the template given by the `#[aoc(fmt = "...")]` attribute among [attrs] of the [item].
+fn template(attrs: &[Attribute], item: &impl Spanned) -> Result<Template> {
+    let mut template = None;
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("aoc")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("fmt") {
+                template = Some(Template::parse(&meta.value()?.parse()?)?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `fmt`"))
+            }
+        })?;
+    }
+
+    template.ok_or_else(|| {
+        Error::new(
+            item.span(),
+            "expected a template, as in #[aoc(fmt = \"...\")]",
+        )
+    })
+}
+
+/// Returns the expression that builds the [path] with each of its [fields] parsed from the matching capture.
+fn construct(path: TokenStream2, fields: &Fields, template: &Template) -> Result<TokenStream2> {
+    let mut values = vec![];
+
+    for (index, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::from(ident.clone()),
+            None => Member::from(index),
+        };
+
+        let name = match &member {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+
+        let positions: Vec<usize> = (0..template.len())
+            .filter(|&i| template.fields[i] == name)
+            .collect();
+
+        let capture = match positions[..] {
+            [capture] => capture,
+            [] => {
+                return Err(Error::new(
+                    template.span,
+                    format!("the template has no placeholder for `{name}`"),
+                ))
+            }
+            _ => {
+                return Err(Error::new(
+                    template.span,
+                    format!("the template has more than one placeholder for `{name}`"),
+                ))
+            }
+        };
+
+        let ty = &field.ty;
+        values.push(quote! {
+            #member: match captures[#capture].parse::<#ty>() {
+                Ok(value) => value,
+                Err(e) => {
+                    let capture = captures[#capture];
+                    return Err(::aoc::Error::new(format!("invalid value `{capture}`: {e}")).at(capture));
+                }
+            }
+        });
+    }
+
+    if let Some(unknown) = template.fields.iter().find(|f| {
+        !fields
+            .iter()
+            .enumerate()
+            .any(|(index, field)| match &field.ident {
+                Some(ident) => ident == f.as_str(),
+                None => index.to_string() == **f,
+            })
+    }) {
+        return Err(Error::new(
+            template.span,
+            format!("no field named `{unknown}`"),
+        ));
+    }
+
+    Ok(quote!(#path { #(#values),* }))
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn error(input: DeriveInput) -> String {
+        expand(&input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn expands_a_struct() {
+        let tokens = expand(&parse_quote! {
+            #[aoc(fmt = "{ax},{ay} -> {bx},{by}")]
+            struct Line {
+                ax: usize,
+                ay: usize,
+                bx: usize,
+                by: usize,
+            }
+        })
+        .unwrap()
+        .to_string();
+
+        assert!(tokens.contains("impl :: std :: str :: FromStr for Line"));
+        assert!(tokens.contains("\"{},{} -> {},{}\""));
+        assert!(tokens.contains("by : match captures [3usize] . parse :: < usize > ()"));
+    }
+
+    #[test]
+    fn expands_an_enum() {
+        let tokens = expand(&parse_quote! {
+            enum Command {
+                #[aoc(fmt = "forward {0}")]
+                Forward(i32),
+                #[aoc(fmt = "stop")]
+                Stop,
+            }
+        })
+        .unwrap()
+        .to_string();
+
+        assert!(tokens.contains("Self :: Forward"));
+        assert!(tokens.contains("Self :: Stop"));
+        assert!(tokens.contains("expected one of `forward {0}`, `stop`"));
+    }
+
+    #[test]
+    fn rejects_a_field_without_a_placeholder() {
+        let message = error(parse_quote! {
+            #[aoc(fmt = "{x}")]
+            struct Point {
+                x: i32,
+                y: i32,
+            }
+        });
+
+        assert_eq!(message, "the template has no placeholder for `y`");
+    }
+
+    #[test]
+    fn rejects_a_placeholder_without_a_field() {
+        let message = error(parse_quote! {
+            #[aoc(fmt = "{x},{z}")]
+            struct Point {
+                x: i32,
+            }
+        });
+
+        assert_eq!(message, "no field named `z`");
+    }
+
+    #[test]
+    fn rejects_adjacent_placeholders() {
+        let message = error(parse_quote! {
+            #[aoc(fmt = "{x}{y}")]
+            struct Point {
+                x: i32,
+                y: i32,
+            }
+        });
+
+        assert_eq!(message, "placeholders must be separated by some text");
+    }
+
+    #[test]
+    fn rejects_unmatched_braces() {
+        let message = error(parse_quote! {
+            #[aoc(fmt = "{ax,{ay}")]
+            struct Point {
+                ax: i32,
+                ay: i32,
+            }
+        });
+
+        assert_eq!(message, "unmatched `{` in the template");
+
+        let message = error(parse_quote! {
+            #[aoc(fmt = "{ax")]
+            struct Point {
+                ax: i32,
+            }
+        });
+
+        assert_eq!(message, "unmatched `{` in the template");
+
+        let message = error(parse_quote! {
+            #[aoc(fmt = "ax}")]
+            struct Point {
+                ax: i32,
+            }
+        });
+
+        assert_eq!(message, "unmatched `}` in the template");
+    }
+
+    #[test]
+    fn rejects_a_missing_template() {
+        let message = error(parse_quote! {
+            struct Point {
+                x: i32,
+            }
+        });
+
+        assert_eq!(message, "expected a template, as in #[aoc(fmt = \"...\")]");
+    }
+}
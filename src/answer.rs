@@ -0,0 +1,167 @@
+use std::fmt::{self, Display};
+
+use crate::array::Array2D;
+
+/// The answer to a part of a puzzle.
+///
+/// Answers are compared by their normalized text, so an integer answer matches the same number recorded as a string,
+/// and a grid matches regardless of trailing whitespace or surrounding blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// An integer answer.
+    Integer(i128),
+    /// A single line of text.
+    Text(String),
+    /// A multi-line rendering, such as letters drawn in a grid, to be read by eye.
+    Grid(String),
+}
+
+impl Answer {
+    /// Returns the answer for the [text], which is a grid if it spans multiple lines.
+    pub fn from_text(text: &str) -> Self {
+        if text.trim().contains('\n') {
+            Answer::Grid(text.to_string())
+        } else {
+            Answer::Text(text.to_string())
+        }
+    }
+
+    /// Returns the grid answer rendering each row of [grid] with `#` for set cells and `.` for unset cells.
+    pub fn from_grid(grid: &Array2D<bool>) -> Self {
+        let rows = grid
+            .iter_rows()
+            .map(|row| row.map(|&v| if v { '#' } else { '.' }).collect::<String>());
+        Answer::Grid(rows.collect::<Vec<_>>().join("\n"))
+    }
+
+    /// Returns the normalized text of the answer, used for comparison and submission.
+    pub fn normalized(&self) -> String {
+        match self {
+            Answer::Integer(n) => n.to_string(),
+            Answer::Text(s) => s.trim().to_string(),
+            Answer::Grid(s) => {
+                let lines: Vec<&str> = s.lines().map(str::trim_end).collect();
+
+                let start = lines
+                    .iter()
+                    .position(|l| !l.is_empty())
+                    .unwrap_or(lines.len());
+                let end = lines
+                    .iter()
+                    .rposition(|l| !l.is_empty())
+                    .map_or(start, |i| i + 1);
+
+                lines[start..end].join("\n")
+            }
+        }
+    }
+
+    /// Returns true if the answer has the same normalized text as the [other] answer.
+    pub fn matches(&self, other: &Answer) -> bool {
+        self.normalized() == other.normalized()
+    }
+
+    /// Returns true if the answer spans multiple lines.
+    pub fn is_multiline(&self) -> bool {
+        self.normalized().contains('\n')
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.normalized())
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        i128::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Integer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::from_text(&s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::from_text(s)
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Answer::Text(c.to_string())
+    }
+}
+
+impl From<&Array2D<bool>> for Answer {
+    fn from(grid: &Array2D<bool>) -> Self {
+        Answer::from_grid(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_an_integer_recorded_as_text() {
+        assert!(Answer::from(42u32).matches(&Answer::from_text("42")));
+        assert!(Answer::from(-7i64).matches(&Answer::from_text(" -7\n")));
+        assert!(!Answer::from(42u32).matches(&Answer::from_text("042")));
+        assert!(!Answer::from(42u32).matches(&Answer::from(43u32)));
+    }
+
+    #[test]
+    fn matches_text_ignoring_surrounding_whitespace() {
+        assert!(Answer::from("abc").matches(&Answer::from_text("  abc\n")));
+        assert!(Answer::from('x').matches(&Answer::from_text("x")));
+        assert!(!Answer::from("abc").matches(&Answer::from("ABC")));
+    }
+
+    #[test]
+    fn trims_grids() {
+        let grid = Answer::from_text("\n\n#..#  \n.##.\n\n");
+
+        assert_eq!(grid, Answer::Grid(String::from("\n\n#..#  \n.##.\n\n")));
+        assert_eq!(grid.normalized(), "#..#\n.##.");
+        assert!(grid.is_multiline());
+
+        // Leading spaces are part of the drawing, so they are kept.
+        assert!(!grid.matches(&Answer::from_text(" #..#\n.##.")));
+    }
+
+    #[test]
+    fn draws_a_grid_of_booleans() {
+        let grid = Array2D::from_slice(&[true, false, false, true], 2, 2);
+        let answer = Answer::from(&grid);
+
+        assert!(answer.matches(&Answer::from_text("#.\n.#\n")));
+        assert_eq!(answer.to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn falls_back_to_text_for_large_u128() {
+        assert_eq!(Answer::from(12u128), Answer::Integer(12));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert!(Answer::from(u128::MAX).matches(&Answer::from_text(&u128::MAX.to_string())));
+        assert!(Answer::from(i128::MAX as u128).matches(&Answer::from(i128::MAX)));
+    }
+}
@@ -0,0 +1,104 @@
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+use crate::{Answer, Part};
+
+/// The directory containing the expected answer files, one `YYYY_DD.toml` per puzzle.
+pub const ANSWERS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/answers");
+
+/// The expected answers to a puzzle, either of which may not be known yet.
+///
+/// Answers are stored as TOML, with integer or string values. A multi-line string is a grid answer:
+///
+/// ```toml
+/// part_one = 1791
+/// part_two = '''
+/// #..#
+/// ####
+/// '''
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Answers {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+impl Answers {
+    /// Returns the expected answers for the puzzle with the specified `YYYY_DD` [name], or no answers if the
+    /// puzzle does not have an answer file.
+    pub fn load(name: &str) -> Result<Answers, String> {
+        let path = path(name);
+
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        let text = std::fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+
+        Answers::parse(&text).map_err(|e| format!("invalid answers in {}: {e}", path.display()))
+    }
+
+    /// Returns the answers in the TOML [text].
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let table: Table = text
+            .parse()
+            .map_err(|e: toml::de::Error| e.message().to_string())?;
+        Answers::from_table(&table)
+    }
+
+    /// Returns the answers stored in the `part_one` and `part_two` keys of the [table].
+    pub fn from_table(table: &Table) -> Result<Answers, String> {
+        Ok(Answers {
+            part_one: table.get("part_one").map(answer_value).transpose()?,
+            part_two: table.get("part_two").map(answer_value).transpose()?,
+        })
+    }
+
+    /// Returns the expected answer to the specified [part], if known.
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+}
+
+/// The result of checking an answer against the expected answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Check {
+    Pass,
+    Fail { expected: Answer },
+    Missing,
+}
+
+impl Check {
+    /// Returns the result of checking the [actual] answer against the [expected] answer.
+    pub fn new(expected: Option<&Answer>, actual: &Answer) -> Check {
+        match expected {
+            Some(expected) if expected.matches(actual) => Check::Pass,
+            Some(expected) => Check::Fail {
+                expected: expected.clone(),
+            },
+            None => Check::Missing,
+        }
+    }
+}
+
+/// Returns the path of the answer file for the puzzle with the specified `YYYY_DD` [name].
+pub fn path(name: &str) -> PathBuf {
+    Path::new(ANSWERS_DIR).join(format!("{name}.toml"))
+}
+
+/// Returns the answer stored in the [value], which must be an integer or a string.
+fn answer_value(value: &Value) -> Result<Answer, String> {
+    match value {
+        Value::Integer(n) => Ok(Answer::from(*n)),
+        Value::String(s) => Ok(Answer::from_text(s)),
+        _ => Err(format!(
+            "expected an integer or string answer, found {}",
+            value.type_str()
+        )),
+    }
+}
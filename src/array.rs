@@ -1,5 +1,18 @@
 use itertools::Itertools;
-use std::ops::{Index, IndexMut};
+use std::ops::{Index, IndexMut, RangeBounds};
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+mod neighborhood;
+mod orientation;
+mod render;
+mod view;
+
+pub use neighborhood::{Grid, Neighborhood};
+pub use orientation::{Orientation, Oriented};
+pub use render::Render;
+pub use view::{ArrayView2D, ArrayViewMut2D};
 
 #[derive(Clone)]
 pub struct Array2D<T> {
@@ -49,6 +62,67 @@ impl<T> Array2D<T> {
         }
     }
 
+    /// Creates a new Array2D from a grid of characters, one row per line, mapping each character with [f]. The
+    /// dimensions are those of the grid, so every line must have the same number of characters.
+    ///
+    /// A character that [f] maps to None is an error attached to that character.
+    pub fn from_grid_with(text: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut values = vec![];
+        let mut rows = 0;
+        let mut cols = None;
+
+        for line in text.lines() {
+            let len = line.chars().count();
+
+            match cols {
+                None => cols = Some(len),
+                Some(cols) if cols != len => {
+                    return Err(Error::new(format!(
+                        "expected a row of {cols} characters, found {len}"
+                    ))
+                    .at(line));
+                }
+                _ => {}
+            }
+
+            for (i, c) in line.char_indices() {
+                let value = f(c).ok_or_else(|| {
+                    Error::new(format!("unexpected character `{c}`")).at(&line[i..i + c.len_utf8()])
+                })?;
+
+                values.push(value);
+            }
+
+            rows += 1;
+        }
+
+        match cols {
+            Some(cols) if cols > 0 => Ok(Array2D { values, rows, cols }),
+            _ => Err(Error::new("expected a grid of characters").at(text)),
+        }
+    }
+
+    /// Creates a new Array2D from a grid of characters, one row per line, converting each character with
+    /// [FromGridChar].
+    pub fn from_grid(text: &str) -> Result<Self>
+    where
+        T: FromGridChar,
+    {
+        Self::from_grid_with(text, T::from_grid_char)
+    }
+
+    /// Creates a new Array2D from a grid of characters, one row per line, converting each character with
+    /// `TryFrom<char>`, as implemented by a cell enum such as `Tile::Wall` for `#`.
+    ///
+    /// The integer types convert from the code point of the character, so their grids are read with
+    /// [Array2D::from_grid] instead.
+    pub fn from_grid_try(text: &str) -> Result<Self>
+    where
+        T: TryFrom<char>,
+    {
+        Self::from_grid_with(text, |c| T::try_from(c).ok())
+    }
+
     /// Returns the number of rows in the array.
     pub fn rows(&self) -> usize {
         self.rows
@@ -137,7 +211,20 @@ impl<T> Array2D<T> {
 
     /// Returns an iterator over each element adjacent to the specified [row] and [col].
     pub fn iter_adjacent(&self, row: usize, col: usize) -> impl Iterator<Item = &T> {
-        MooreNeighborhoodIterator::new((row, col), false).flat_map(|i| self.get_tuple(i))
+        static MOORE: Neighborhood = Neighborhood::MOORE;
+
+        MOORE
+            .indices((row, col), self.rows, self.cols)
+            .map(|i| &self[i])
+    }
+
+    /// Returns an iterator over each value in the [neighborhood] of the specified [index], with its 2D index.
+    pub fn neighbors<'a, 'n>(
+        &'a self,
+        index: Array2DIndex,
+        neighborhood: &'n Neighborhood,
+    ) -> impl Iterator<Item = (Array2DIndex, &'a T)> + use<'a, 'n, T> {
+        neighborhood.neighbors(index, self)
     }
 
     /// Returns an iterator that enumerates each value in the array with its 2D index.
@@ -149,8 +236,152 @@ impl<T> Array2D<T> {
     pub fn enumerate_mut(&mut self) -> impl Iterator<Item = (Array2DIndex, &mut T)> {
         self.iter_indices().zip(self.iter_mut())
     }
+
+    /// Returns a view of the [rows] and [cols] of the array, without copying it.
+    ///
+    /// Panics if either range is outside the array.
+    pub fn view(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> ArrayView2D<'_, T> {
+        let rows = view::range(rows, self.rows);
+        let cols = view::range(cols, self.cols);
+
+        ArrayView2D::new(self, rows, cols)
+    }
+
+    /// Returns a view of the [rows] and [cols] of the array that allows modifying each value, without copying it.
+    ///
+    /// Panics if either range is outside the array.
+    pub fn view_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> ArrayViewMut2D<'_, T> {
+        let rows = view::range(rows, self.rows);
+        let cols = view::range(cols, self.cols);
+
+        ArrayViewMut2D::new(self, rows, cols)
+    }
+
+    /// Returns a view of the array in the [orientation], without copying it.
+    pub fn oriented(&self, orientation: Orientation) -> Oriented<'_, T> {
+        Oriented::new(self, orientation)
+    }
+
+    /// Returns an iterator over views of the array in each of the 8 orientations, the rotations first.
+    pub fn orientations(&self) -> impl Iterator<Item = Oriented<'_, T>> {
+        Orientation::ALL.into_iter().map(|o| self.oriented(o))
+    }
+
+    /// Returns a new Array2D with the rows and columns of this one swapped.
+    pub fn transpose(&self) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        self.oriented(Orientation::TRANSPOSE).to_array()
+    }
+
+    /// Returns a new Array2D with the values of this one rotated a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        self.oriented(Orientation::ROTATE_CW).to_array()
+    }
+
+    /// Returns a new Array2D with the values of this one rotated a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        self.oriented(Orientation::ROTATE_CCW).to_array()
+    }
+
+    /// Returns a new Array2D with each row of this one reversed.
+    pub fn flip_horizontal(&self) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        self.oriented(Orientation::FLIP_HORIZONTAL).to_array()
+    }
+
+    /// Returns a new Array2D with each column of this one reversed.
+    pub fn flip_vertical(&self) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        self.oriented(Orientation::FLIP_VERTICAL).to_array()
+    }
+
+    /// Returns a configurable rendering of the array, with each cell formatted by its `Display` implementation until
+    /// another formatter is given.
+    pub fn render(&self) -> Render<'_, T>
+    where
+        T: std::fmt::Display,
+    {
+        Render::new(self, Box::new(|value| value.to_string()))
+    }
+
+    /// Returns a configurable rendering of the array, with each cell formatted by [f].
+    pub fn render_with<'a, D: std::fmt::Display>(
+        &'a self,
+        f: impl Fn(&T) -> D + 'a,
+    ) -> Render<'a, T> {
+        Render::new(self, Box::new(move |value| f(value).to_string()))
+    }
+}
+
+impl<T: FromGridChar> FromStr for Array2D<T> {
+    type Err = Error;
+
+    /// Parses a grid of characters with [Array2D::from_grid].
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_grid(s)
+    }
+}
+
+/// A value that can be read from a single character of a grid by [Array2D::from_grid].
+///
+/// This is deliberately not `TryFrom<char>`, which the integer types implement as a conversion from the code point, so
+/// that `"123".parse::<Array2D<u32>>()` gives the digits rather than `[49, 50, 51]`. A character is itself, an
+/// unsigned integer is a decimal digit, and a bool is `#` for true or `.` for false. A type that implements
+/// `TryFrom<char>` is read with [Array2D::from_grid_try], and any other mapping can be given to
+/// [Array2D::from_grid_with].
+pub trait FromGridChar: Sized {
+    /// Returns the value of the character [c], or None if it is not valid in a grid of this type.
+    fn from_grid_char(c: char) -> Option<Self>;
 }
 
+impl FromGridChar for char {
+    fn from_grid_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+impl FromGridChar for bool {
+    fn from_grid_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_from_grid_char_digit {
+    ($($t:ty),*) => {
+        $(impl FromGridChar for $t {
+            fn from_grid_char(c: char) -> Option<Self> {
+                c.to_digit(10).map(|d| d as $t)
+            }
+        })*
+    };
+}
+
+impl_from_grid_char_digit!(u8, u16, u32, u64, u128, usize);
+
 impl<T> Index<Array2DIndex> for Array2D<T> {
     type Output = T;
 
@@ -165,69 +396,65 @@ impl<T> IndexMut<Array2DIndex> for Array2D<T> {
     }
 }
 
-/// Data structure for iterating over the Moore neighborhood of a 2D index.
-struct MooreNeighborhoodIterator {
-    cells: [Option<Array2DIndex>; 9],
-    index: usize,
-}
-
-impl MooreNeighborhoodIterator {
-    pub fn new(center: Array2DIndex, include_center: bool) -> MooreNeighborhoodIterator {
-        let mut cells = [None; 9];
-
-        cells[0] = moore_neighbor(center, 0);
-        cells[1] = moore_neighbor(center, 1);
-        cells[2] = moore_neighbor(center, 2);
-        cells[3] = moore_neighbor(center, 3);
-        cells[4] = moore_neighbor(center, 4);
-        cells[5] = moore_neighbor(center, 5);
-        cells[6] = moore_neighbor(center, 6);
-        cells[7] = moore_neighbor(center, 7);
-        cells[8] = moore_neighbor(center, 8);
-
-        if !include_center {
-            cells[4] = None;
-        }
-
-        Self { cells, index: 0 }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_integer_grids_as_digits() {
+        let grid: Array2D<u32> = "123\n456".parse().unwrap();
+        assert_eq!(
+            grid.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert!("12x".parse::<Array2D<u8>>().is_err());
     }
-}
 
-impl Iterator for MooreNeighborhoodIterator {
-    type Item = Array2DIndex;
+    #[test]
+    fn parses_char_and_bool_grids() {
+        let chars: Array2D<char> = "ab\ncd".parse().unwrap();
+        assert_eq!((chars.rows(), chars.cols(), chars[(1, 0)]), (2, 2, 'c'));
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let mut next = self.index;
+        let cells: Array2D<bool> = "#.\n.#".parse().unwrap();
+        assert_eq!(
+            cells.iter().copied().collect::<Vec<_>>(),
+            vec![true, false, false, true]
+        );
+    }
 
-        while next < self.cells.len() && self.cells[next].is_none() {
-            next += 1;
-        }
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
 
-        self.index = next + 1;
+    impl TryFrom<char> for Tile {
+        type Error = ();
 
-        if next < self.cells.len() {
-            return self.cells[next];
+        fn try_from(c: char) -> std::result::Result<Self, ()> {
+            match c {
+                '.' => Ok(Tile::Open),
+                '#' => Ok(Tile::Wall),
+                _ => Err(()),
+            }
         }
-
-        None
     }
-}
 
-/// Returns the Moore neighbor relative to the specified index, or None if the the coordinate would overflow.
-#[allow(clippy::manual_range_patterns)]
-fn moore_neighbor((row, col): Array2DIndex, moore: usize) -> Option<Array2DIndex> {
-    Option::zip(
-        match moore {
-            0 | 1 | 2 => row.checked_sub(1),
-            3 | 4 | 5 => Some(row),
-            6 | 7 | 8 => row.checked_add(1),
-            _ => panic!(),
-        },
-        match moore {
-            0 | 3 | 6 => col.checked_sub(1),
-            1 | 4 | 7 => Some(col),
-            2 | 5 | 8 => col.checked_add(1),
-            _ => panic!(),
-        },
-    )
+    #[test]
+    fn parses_grids_of_cells_that_convert_from_char() {
+        let tiles = Array2D::<Tile>::from_grid_try(".#\n#.").unwrap();
+        assert_eq!(
+            tiles.iter().copied().collect::<Vec<_>>(),
+            vec![Tile::Open, Tile::Wall, Tile::Wall, Tile::Open]
+        );
+
+        let text = "..\n.x";
+        let error = Array2D::<Tile>::from_grid_try(text)
+            .unwrap_err()
+            .locate(text);
+        let location = error.location().unwrap();
+
+        assert_eq!(error.message(), "unexpected character `x`");
+        assert_eq!((location.line, location.column), (2, 2));
+    }
 }
@@ -0,0 +1,235 @@
+use std::borrow::Cow;
+
+use super::{Array2D, Array2DIndex, ArrayView2D, ArrayViewMut2D, Oriented};
+
+/// A rectangular grid of values that can be looked up by row and column, implemented by [Array2D] and its views so
+/// that a [Neighborhood] can be used with any of them.
+pub trait Grid {
+    type Value;
+
+    /// Returns the number of rows in the grid.
+    fn rows(&self) -> usize;
+
+    /// Returns the number of columns in the grid.
+    fn cols(&self) -> usize;
+
+    /// Returns a reference to the value at the specified [row] and [col], if it exists.
+    fn get(&self, row: usize, col: usize) -> Option<&Self::Value>;
+}
+
+impl<T> Grid for Array2D<T> {
+    type Value = T;
+
+    fn rows(&self) -> usize {
+        Array2D::rows(self)
+    }
+
+    fn cols(&self) -> usize {
+        Array2D::cols(self)
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        Array2D::get(self, row, col)
+    }
+}
+
+impl<T> Grid for ArrayView2D<'_, T> {
+    type Value = T;
+
+    fn rows(&self) -> usize {
+        ArrayView2D::rows(self)
+    }
+
+    fn cols(&self) -> usize {
+        ArrayView2D::cols(self)
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        ArrayView2D::get(self, row, col)
+    }
+}
+
+impl<T> Grid for ArrayViewMut2D<'_, T> {
+    type Value = T;
+
+    fn rows(&self) -> usize {
+        ArrayViewMut2D::rows(self)
+    }
+
+    fn cols(&self) -> usize {
+        ArrayViewMut2D::cols(self)
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        ArrayViewMut2D::get(self, row, col)
+    }
+}
+
+impl<T> Grid for Oriented<'_, T> {
+    type Value = T;
+
+    fn rows(&self) -> usize {
+        Oriented::rows(self)
+    }
+
+    fn cols(&self) -> usize {
+        Oriented::cols(self)
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        Oriented::get(self, row, col)
+    }
+}
+
+/// The cells around a center cell, given as (row, col) offsets from it.
+///
+/// Like [Neighborhood::indices], [Neighborhood::neighbors] takes the center first, as does [Array2D::neighbors].
+///
+/// The common neighborhoods are provided as constants, and larger or custom ones are built from offsets. Only the
+/// neighbors inside the grid are returned, in the order of the offsets:
+///
+/// ```ignore
+/// for ((row, col), &height) in Neighborhood::VON_NEUMANN.neighbors((row, col), &heights) {
+///     ...
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Neighborhood {
+    offsets: Cow<'static, [(isize, isize)]>,
+}
+
+impl Neighborhood {
+    /// The 4 cells orthogonally adjacent to the center, in row-major order.
+    pub const VON_NEUMANN: Neighborhood =
+        Neighborhood::from_static(&[(-1, 0), (0, -1), (0, 1), (1, 0)]);
+
+    /// The 8 cells orthogonally or diagonally adjacent to the center, in row-major order.
+    pub const MOORE: Neighborhood = Neighborhood::from_static(&[
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ]);
+
+    const fn from_static(offsets: &'static [(isize, isize)]) -> Self {
+        Neighborhood {
+            offsets: Cow::Borrowed(offsets),
+        }
+    }
+
+    /// Creates a new Neighborhood of the cells at the [offsets] from the center, in that order.
+    pub fn from_offsets(offsets: impl IntoIterator<Item = (isize, isize)>) -> Self {
+        Neighborhood {
+            offsets: Cow::Owned(offsets.into_iter().collect()),
+        }
+    }
+
+    /// Creates a new Neighborhood of the cells within a Manhattan distance of [radius] from the center, in row-major
+    /// order.
+    pub fn von_neumann(radius: usize) -> Self {
+        Self::within(radius, |dr, dc| dr.unsigned_abs() + dc.unsigned_abs())
+    }
+
+    /// Creates a new Neighborhood of the cells within a Chebyshev distance of [radius] from the center, which is the
+    /// square around it, in row-major order.
+    pub fn moore(radius: usize) -> Self {
+        Self::within(radius, |dr, dc| dr.unsigned_abs().max(dc.unsigned_abs()))
+    }
+
+    /// Returns the cells other than the center within the [radius] by the [distance] function, in row-major order.
+    fn within(radius: usize, distance: impl Fn(isize, isize) -> usize) -> Self {
+        let r = radius as isize;
+
+        let offsets = (-r..=r).flat_map(|dr| (-r..=r).map(move |dc| (dr, dc)));
+        let offsets = offsets.filter(|&(dr, dc)| (dr, dc) != (0, 0) && distance(dr, dc) <= radius);
+
+        Self::from_offsets(offsets)
+    }
+
+    /// Returns the neighborhood with the center included, placed in row-major order if the offsets are.
+    pub fn with_center(self) -> Self {
+        if self.offsets.contains(&(0, 0)) {
+            return self;
+        }
+
+        let mut offsets = self.offsets.into_owned();
+        let position = offsets.iter().position(|&offset| offset > (0, 0));
+
+        offsets.insert(position.unwrap_or(offsets.len()), (0, 0));
+
+        Neighborhood {
+            offsets: Cow::Owned(offsets),
+        }
+    }
+
+    /// Returns the neighborhood with the center removed.
+    pub fn without_center(self) -> Self {
+        Self::from_offsets(self.offsets.iter().copied().filter(|&o| o != (0, 0)))
+    }
+
+    /// Returns the offsets of the neighborhood.
+    pub fn offsets(&self) -> &[(isize, isize)] {
+        &self.offsets
+    }
+
+    /// Returns an iterator over the index of each neighbor of the [center] in a grid of [rows] by [cols].
+    pub fn indices(
+        &self,
+        (row, col): Array2DIndex,
+        rows: usize,
+        cols: usize,
+    ) -> impl Iterator<Item = Array2DIndex> + '_ {
+        self.offsets.iter().filter_map(move |&(dr, dc)| {
+            let row = row.checked_add_signed(dr).filter(|&row| row < rows)?;
+            let col = col.checked_add_signed(dc).filter(|&col| col < cols)?;
+
+            Some((row, col))
+        })
+    }
+
+    /// Returns an iterator over each neighbor of the [center] in the [grid], with its index.
+    pub fn neighbors<'g, G: Grid>(
+        &self,
+        center: Array2DIndex,
+        grid: &'g G,
+    ) -> impl Iterator<Item = (Array2DIndex, &'g G::Value)> + use<'_, 'g, G> {
+        self.indices(center, grid.rows(), grid.cols())
+            .filter_map(|(row, col)| Some(((row, col), grid.get(row, col)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn takes_the_center_first_from_either_side() {
+        let grid = Array2D::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3);
+
+        let from_grid: Vec<_> = grid.neighbors((0, 1), &Neighborhood::VON_NEUMANN).collect();
+        let from_neighborhood: Vec<_> =
+            Neighborhood::VON_NEUMANN.neighbors((0, 1), &grid).collect();
+
+        assert_eq!(from_grid, vec![((0, 0), &1), ((0, 2), &3), ((1, 1), &5)]);
+        assert_eq!(from_grid, from_neighborhood);
+    }
+
+    #[test]
+    fn builds_neighborhoods_by_radius() {
+        assert_eq!(Neighborhood::moore(1), Neighborhood::MOORE);
+        assert_eq!(Neighborhood::von_neumann(1), Neighborhood::VON_NEUMANN);
+        assert_eq!(Neighborhood::von_neumann(2).offsets().len(), 12);
+        assert_eq!(Neighborhood::moore(2).offsets().len(), 24);
+
+        let centered = Neighborhood::VON_NEUMANN.with_center();
+        assert_eq!(
+            centered.offsets(),
+            &[(-1, 0), (0, -1), (0, 0), (0, 1), (1, 0)]
+        );
+        assert_eq!(centered.without_center(), Neighborhood::VON_NEUMANN);
+    }
+}
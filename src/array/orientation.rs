@@ -0,0 +1,422 @@
+use std::ops::Index;
+
+use super::{Array2D, Array2DIndex};
+
+/// One of the 8 ways to lay a grid back onto itself by rotating and mirroring it, which are the symmetries of a
+/// square (the dihedral group of order 8).
+///
+/// An orientation is a transpose, done or not, followed by flipping the rows and the columns, done or not. Any
+/// sequence of rotations and flips reduces to one of these, which [Orientation::then] computes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    transpose: bool,
+    flip_rows: bool,
+    flip_cols: bool,
+}
+
+impl Orientation {
+    /// The grid as it is.
+    pub const IDENTITY: Orientation = Orientation::new(false, false, false);
+    /// The grid rotated a quarter turn clockwise.
+    pub const ROTATE_CW: Orientation = Orientation::new(true, true, false);
+    /// The grid rotated a half turn.
+    pub const ROTATE_180: Orientation = Orientation::new(false, true, true);
+    /// The grid rotated a quarter turn counterclockwise.
+    pub const ROTATE_CCW: Orientation = Orientation::new(true, false, true);
+    /// The grid mirrored left to right, reversing each row.
+    pub const FLIP_HORIZONTAL: Orientation = Orientation::new(false, false, true);
+    /// The grid mirrored top to bottom, reversing each column.
+    pub const FLIP_VERTICAL: Orientation = Orientation::new(false, true, false);
+    /// The grid mirrored along its main diagonal, swapping rows and columns.
+    pub const TRANSPOSE: Orientation = Orientation::new(true, false, false);
+    /// The grid mirrored along its other diagonal.
+    pub const ANTI_TRANSPOSE: Orientation = Orientation::new(true, true, true);
+
+    /// Every orientation, the rotations first.
+    pub const ALL: [Orientation; 8] = [
+        Orientation::IDENTITY,
+        Orientation::ROTATE_CW,
+        Orientation::ROTATE_180,
+        Orientation::ROTATE_CCW,
+        Orientation::FLIP_HORIZONTAL,
+        Orientation::FLIP_VERTICAL,
+        Orientation::TRANSPOSE,
+        Orientation::ANTI_TRANSPOSE,
+    ];
+
+    const fn new(transpose: bool, flip_rows: bool, flip_cols: bool) -> Self {
+        Orientation {
+            transpose,
+            flip_rows,
+            flip_cols,
+        }
+    }
+
+    /// Returns the orientation of applying this one and then [next].
+    pub fn then(self, next: Orientation) -> Orientation {
+        // The flips of [next] apply to the rows and columns of the grid this one produces, which are the columns and
+        // rows of the original if this one transposes it.
+
+        let (next_rows, next_cols) = if self.transpose {
+            (next.flip_cols, next.flip_rows)
+        } else {
+            (next.flip_rows, next.flip_cols)
+        };
+
+        Orientation {
+            transpose: self.transpose ^ next.transpose,
+            flip_rows: self.flip_rows ^ next_rows,
+            flip_cols: self.flip_cols ^ next_cols,
+        }
+    }
+
+    /// Returns the orientation that undoes this one.
+    pub fn inverse(self) -> Orientation {
+        Orientation::ALL
+            .into_iter()
+            .find(|&o| self.then(o) == Orientation::IDENTITY)
+            .unwrap()
+    }
+
+    /// Returns true if the orientation swaps the number of rows and columns.
+    pub fn is_transposed(self) -> bool {
+        self.transpose
+    }
+
+    /// Returns the index in a grid of [rows] by [cols] of the cell found at [index] once it is oriented.
+    pub fn source_index(self, (row, col): Array2DIndex, rows: usize, cols: usize) -> Array2DIndex {
+        let (row, col) = if self.transpose {
+            (col, row)
+        } else {
+            (row, col)
+        };
+
+        let row = if self.flip_rows { rows - 1 - row } else { row };
+        let col = if self.flip_cols { cols - 1 - col } else { col };
+
+        (row, col)
+    }
+}
+
+/// A read-only view of an [Array2D] in another [Orientation], created by [Array2D::oriented]. Nothing is copied, so
+/// each cell is looked up in the array as it is read.
+pub struct Oriented<'a, T> {
+    array: &'a Array2D<T>,
+    orientation: Orientation,
+}
+
+impl<'a, T> Oriented<'a, T> {
+    pub(super) fn new(array: &'a Array2D<T>, orientation: Orientation) -> Self {
+        Oriented { array, orientation }
+    }
+
+    /// Returns the orientation of the view, relative to the array.
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Returns the view in the [orientation] relative to this one.
+    pub fn oriented(&self, orientation: Orientation) -> Oriented<'a, T> {
+        Oriented::new(self.array, self.orientation.then(orientation))
+    }
+
+    /// Returns the view with rows and columns swapped.
+    pub fn transpose(&self) -> Oriented<'a, T> {
+        self.oriented(Orientation::TRANSPOSE)
+    }
+
+    /// Returns the view rotated a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Oriented<'a, T> {
+        self.oriented(Orientation::ROTATE_CW)
+    }
+
+    /// Returns the view rotated a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Oriented<'a, T> {
+        self.oriented(Orientation::ROTATE_CCW)
+    }
+
+    /// Returns the view mirrored left to right.
+    pub fn flip_horizontal(&self) -> Oriented<'a, T> {
+        self.oriented(Orientation::FLIP_HORIZONTAL)
+    }
+
+    /// Returns the view mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Oriented<'a, T> {
+        self.oriented(Orientation::FLIP_VERTICAL)
+    }
+
+    /// Returns the number of rows in the view.
+    pub fn rows(&self) -> usize {
+        if self.orientation.transpose {
+            self.array.cols()
+        } else {
+            self.array.rows()
+        }
+    }
+
+    /// Returns the number of columns in the view.
+    pub fn cols(&self) -> usize {
+        if self.orientation.transpose {
+            self.array.rows()
+        } else {
+            self.array.cols()
+        }
+    }
+
+    /// Returns a reference to the value at the specified [row] and [col] of the view, if it exists.
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row >= self.rows() || col >= self.cols() {
+            return None;
+        }
+
+        let (row, col) =
+            self.orientation
+                .source_index((row, col), self.array.rows(), self.array.cols());
+
+        self.array.get(row, col)
+    }
+
+    /// Returns a reference to the value at the specified [index] of the view, if it exists.
+    pub fn get_tuple(&self, (row, col): Array2DIndex) -> Option<&'a T> {
+        self.get(row, col)
+    }
+
+    /// Returns an iterator over each value in the view, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let view = *self;
+        self.iter_indices().map(move |i| view.cell(i))
+    }
+
+    /// Returns an iterator over each 2D index in the view, in row-major order.
+    pub fn iter_indices(&self) -> impl Iterator<Item = Array2DIndex> {
+        let cols = self.cols();
+        (0..self.rows()).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    /// Returns an iterator over each value in the specified [row] of the view.
+    pub fn iter_row(&self, row: usize) -> impl Iterator<Item = &'a T> + 'a {
+        assert!(row < self.rows());
+        let view = *self;
+        (0..self.cols()).map(move |col| view.cell((row, col)))
+    }
+
+    /// Returns an iterator over each value in the specified [col] of the view.
+    pub fn iter_col(&self, col: usize) -> impl Iterator<Item = &'a T> + 'a {
+        assert!(col < self.cols());
+        let view = *self;
+        (0..self.rows()).map(move |row| view.cell((row, col)))
+    }
+
+    /// Returns an iterator over each row in the view.
+    pub fn iter_rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + 'a> + 'a {
+        let view = *self;
+        (0..self.rows()).map(move |row| view.iter_row(row))
+    }
+
+    /// Returns an iterator over each column in the view.
+    pub fn iter_cols(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + 'a> + 'a {
+        let view = *self;
+        (0..self.cols()).map(move |col| view.iter_col(col))
+    }
+
+    /// Returns an iterator that enumerates each value in the view with its 2D index.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Array2DIndex, &'a T)> + 'a {
+        let view = *self;
+        self.iter_indices().map(move |i| (i, view.cell(i)))
+    }
+
+    fn cell(self, (row, col): Array2DIndex) -> &'a T {
+        self.get(row, col).unwrap()
+    }
+
+    /// Returns a new Array2D with the values of the view.
+    pub fn to_array(&self) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        Array2D {
+            values: self.iter().cloned().collect(),
+            rows: self.rows(),
+            cols: self.cols(),
+        }
+    }
+}
+
+// Derived impls would require T: Clone, though only the reference is copied.
+
+impl<T> Clone for Oriented<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Oriented<'_, T> {}
+
+impl<'a, T> Index<Array2DIndex> for Oriented<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: Array2DIndex) -> &T {
+        self.cell(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the array of the [rows], which must all be the same length.
+    fn array(rows: &[&[u32]]) -> Array2D<u32> {
+        Array2D::from_slice(&rows.concat(), rows.len(), rows[0].len())
+    }
+
+    /// The array all the expected orientations are of, which is not square so that transposing it shows.
+    fn original() -> Array2D<u32> {
+        array(&[&[1, 2, 3], &[4, 5, 6]])
+    }
+
+    /// Returns the rows of the [array], for comparing arrays.
+    fn rows(array: &Array2D<u32>) -> Vec<Vec<u32>> {
+        array
+            .iter_rows()
+            .map(|row| row.copied().collect())
+            .collect()
+    }
+
+    #[test]
+    fn orients_a_non_square_array() {
+        let expected = [
+            (Orientation::IDENTITY, array(&[&[1, 2, 3], &[4, 5, 6]])),
+            (Orientation::ROTATE_CW, array(&[&[4, 1], &[5, 2], &[6, 3]])),
+            (Orientation::ROTATE_180, array(&[&[6, 5, 4], &[3, 2, 1]])),
+            (Orientation::ROTATE_CCW, array(&[&[3, 6], &[2, 5], &[1, 4]])),
+            (
+                Orientation::FLIP_HORIZONTAL,
+                array(&[&[3, 2, 1], &[6, 5, 4]]),
+            ),
+            (Orientation::FLIP_VERTICAL, array(&[&[4, 5, 6], &[1, 2, 3]])),
+            (Orientation::TRANSPOSE, array(&[&[1, 4], &[2, 5], &[3, 6]])),
+            (
+                Orientation::ANTI_TRANSPOSE,
+                array(&[&[6, 3], &[5, 2], &[4, 1]]),
+            ),
+        ];
+
+        let original = original();
+
+        for (orientation, expected) in expected {
+            let view = original.oriented(orientation);
+
+            assert_eq!(view.rows(), expected.rows(), "{orientation:?}");
+            assert_eq!(view.cols(), expected.cols(), "{orientation:?}");
+            assert_eq!(rows(&view.to_array()), rows(&expected), "{orientation:?}");
+            assert_eq!(view.get(expected.rows(), 0), None, "{orientation:?}");
+        }
+    }
+
+    #[test]
+    fn matches_the_owned_copies() {
+        let original = original();
+
+        let view = original.oriented(Orientation::IDENTITY);
+
+        assert_eq!(
+            rows(&original.rotate_cw()),
+            rows(&view.rotate_cw().to_array())
+        );
+        assert_eq!(
+            rows(&original.rotate_ccw()),
+            rows(&view.rotate_ccw().to_array())
+        );
+        assert_eq!(
+            rows(&original.transpose()),
+            rows(&view.transpose().to_array())
+        );
+        assert_eq!(
+            rows(&original.flip_horizontal()),
+            rows(&view.flip_horizontal().to_array())
+        );
+        assert_eq!(
+            rows(&original.flip_vertical()),
+            rows(&view.flip_vertical().to_array())
+        );
+    }
+
+    #[test]
+    fn composes_views_like_copies() {
+        let original = original();
+
+        for a in Orientation::ALL {
+            for b in Orientation::ALL {
+                let composed = original.oriented(a).oriented(b);
+                let copied = original.oriented(a).to_array().oriented(b).to_array();
+
+                assert_eq!(
+                    rows(&composed.to_array()),
+                    rows(&copied),
+                    "{a:?} then {b:?}"
+                );
+                assert_eq!(composed.orientation(), a.then(b), "{a:?} then {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn inverts_each_orientation() {
+        let original = original();
+
+        for orientation in Orientation::ALL {
+            let inverse = orientation.inverse();
+
+            assert_eq!(orientation.then(inverse), Orientation::IDENTITY);
+            assert_eq!(inverse.then(orientation), Orientation::IDENTITY);
+            let restored = original.oriented(orientation).oriented(inverse).to_array();
+            assert_eq!(rows(&restored), rows(&original));
+        }
+
+        assert_eq!(Orientation::ROTATE_CW.inverse(), Orientation::ROTATE_CCW);
+        assert_eq!(Orientation::TRANSPOSE.inverse(), Orientation::TRANSPOSE);
+    }
+
+    #[test]
+    fn composes_rotations() {
+        let cw = Orientation::ROTATE_CW;
+
+        assert_eq!(cw.then(cw), Orientation::ROTATE_180);
+        assert_eq!(cw.then(cw).then(cw), Orientation::ROTATE_CCW);
+        assert_eq!(cw.then(Orientation::ROTATE_CCW), Orientation::IDENTITY);
+        assert_eq!(
+            Orientation::FLIP_HORIZONTAL.then(Orientation::FLIP_VERTICAL),
+            Orientation::ROTATE_180
+        );
+        assert_eq!(
+            Orientation::TRANSPOSE.then(Orientation::ROTATE_180),
+            Orientation::ANTI_TRANSPOSE
+        );
+    }
+
+    #[test]
+    fn finds_the_source_of_each_index() {
+        // The top left of the array rotated clockwise is the bottom left of the original.
+        assert_eq!(Orientation::ROTATE_CW.source_index((0, 0), 2, 3), (1, 0));
+        assert_eq!(Orientation::ROTATE_CW.source_index((2, 1), 2, 3), (0, 2));
+        assert_eq!(
+            Orientation::ANTI_TRANSPOSE.source_index((0, 1), 2, 3),
+            (0, 2)
+        );
+        assert_eq!(Orientation::IDENTITY.source_index((1, 2), 2, 3), (1, 2));
+    }
+
+    #[test]
+    fn iterates_rows_and_cols_of_a_view() {
+        let original = original();
+        let view = original.oriented(Orientation::ROTATE_CW);
+
+        let rows: Vec<Vec<u32>> = view.iter_rows().map(|row| row.copied().collect()).collect();
+        let cols: Vec<Vec<u32>> = view.iter_cols().map(|col| col.copied().collect()).collect();
+
+        assert_eq!(rows, vec![vec![4, 1], vec![5, 2], vec![6, 3]]);
+        assert_eq!(cols, vec![vec![4, 5, 6], vec![1, 2, 3]]);
+        assert_eq!(view[(2, 0)], 6);
+        assert_eq!(view.enumerate().nth(3), Some(((1, 1), &2)));
+    }
+}
@@ -0,0 +1,250 @@
+use std::fmt::{self, Debug, Display};
+use std::ops::Range;
+
+use super::Array2D;
+
+/// The largest number of rows or columns shown by the `Debug` rendering of an [Array2D].
+const DEBUG_VIEWPORT: usize = 64;
+
+/// A configurable text rendering of an [Array2D], created by [Array2D::render] or [Array2D::render_with].
+///
+/// Each cell is formatted with the cell formatter, and the cells of each column are right-aligned to the widest of
+/// them, so numbers line up. Cells are separated by a space, unless every cell is a single character, in which case
+/// the grid is rendered as it would appear in a puzzle input:
+///
+/// ```ignore
+/// println!("{}", grid.render().cells(|&b| if b { '#' } else { '.' }).headers(true).viewport(0..10, 0..40));
+/// ```
+pub struct Render<'a, T> {
+    array: &'a Array2D<T>,
+    format: Box<dyn Fn(&T) -> String + 'a>,
+    headers: bool,
+    rows: Range<usize>,
+    cols: Range<usize>,
+}
+
+impl<'a, T> Render<'a, T> {
+    pub(super) fn new(array: &'a Array2D<T>, format: Box<dyn Fn(&T) -> String + 'a>) -> Self {
+        Render {
+            array,
+            format,
+            headers: false,
+            rows: 0..array.rows(),
+            cols: 0..array.cols(),
+        }
+    }
+
+    /// Returns the rendering with each cell formatted by [f].
+    pub fn cells<D: Display>(self, f: impl Fn(&T) -> D + 'a) -> Self {
+        Render {
+            format: Box::new(move |value| f(value).to_string()),
+            ..self
+        }
+    }
+
+    /// Returns the rendering with the index of each row and column shown beside the grid if [headers] is true.
+    pub fn headers(self, headers: bool) -> Self {
+        Render { headers, ..self }
+    }
+
+    /// Returns the rendering cropped to the [rows] and [cols], which are clamped to the bounds of the array.
+    pub fn viewport(self, rows: Range<usize>, cols: Range<usize>) -> Self {
+        let rows = clamp(rows, self.array.rows());
+        let cols = clamp(cols, self.array.cols());
+
+        Render { rows, cols, ..self }
+    }
+
+    fn is_cropped(&self) -> bool {
+        self.rows.len() < self.array.rows() || self.cols.len() < self.array.cols()
+    }
+}
+
+impl<T> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells: Vec<Vec<String>> = self
+            .rows
+            .clone()
+            .map(|row| {
+                self.cols
+                    .clone()
+                    .map(|col| (self.format)(&self.array[(row, col)]))
+                    .collect()
+            })
+            .collect();
+
+        let mut widths: Vec<usize> = self
+            .cols
+            .clone()
+            .enumerate()
+            .map(|(i, col)| {
+                let header = if self.headers { digits(col) } else { 0 };
+                let cells = cells.iter().map(|row| row[i].chars().count());
+                cells.max().unwrap_or(0).max(header)
+            })
+            .collect();
+
+        let compact = !self.headers && widths.iter().all(|&w| w <= 1);
+
+        if compact {
+            widths.iter_mut().for_each(|w| *w = 1);
+        }
+
+        let separator = if compact { "" } else { " " };
+        let row_header_width = digits(self.rows.end.saturating_sub(1));
+
+        let mut lines = vec![];
+
+        if self.headers {
+            let headers = self.cols.clone().zip(&widths);
+            let headers = headers.map(|(col, &width)| format!("{col:>width$}"));
+            let headers = headers.collect::<Vec<_>>().join(separator);
+
+            lines.push(format!("{:row_header_width$} {headers}", ""));
+        }
+
+        for (row, cells) in self.rows.clone().zip(&cells) {
+            let cells = cells.iter().zip(&widths);
+            let cells = cells.map(|(cell, &width)| format!("{cell:>width$}"));
+            let cells = cells.collect::<Vec<_>>().join(separator);
+
+            if self.headers {
+                lines.push(format!("{row:>row_header_width$} {cells}"));
+            } else {
+                lines.push(cells);
+            }
+        }
+
+        if self.is_cropped() {
+            lines.push(format!(
+                "(rows {}..{} and columns {}..{} of {}x{})",
+                self.rows.start,
+                self.rows.end,
+                self.cols.start,
+                self.cols.end,
+                self.array.rows(),
+                self.array.cols()
+            ));
+        }
+
+        let lines: Vec<&str> = lines.iter().map(|line| line.trim_end()).collect();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+/// Renders each cell with its `Display` implementation.
+impl<T: Display> Display for Array2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+/// Renders the dimensions of the array, then each cell with its `Debug` implementation, cropped to the top left
+/// corner of a large array.
+impl<T: Debug> Debug for Array2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let render = Render::new(self, Box::new(|value| format!("{value:?}")))
+            .headers(true)
+            .viewport(0..DEBUG_VIEWPORT, 0..DEBUG_VIEWPORT)
+            .to_string();
+
+        write!(f, "Array2D {}x{}", self.rows(), self.cols())?;
+
+        if !render.is_empty() {
+            write!(f, "\n{render}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the [range] with both ends no greater than [len].
+fn clamp(range: Range<usize>, len: usize) -> Range<usize> {
+    range.start.min(len)..range.end.min(len).max(range.start.min(len))
+}
+
+/// Returns the number of decimal digits in [n].
+fn digits(n: usize) -> usize {
+    n.checked_ilog10().map_or(1, |d| d as usize + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_single_characters_compactly() {
+        let array = Array2D::from_slice(&['#', '.', '.', '#'], 2, 2);
+        assert_eq!(array.to_string(), "#.\n.#");
+
+        let array = Array2D::from_slice(&[true, false, false, true], 2, 2);
+        let render = array.render_with(|&b| if b { '#' } else { '.' });
+        assert_eq!(render.to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn aligns_each_column_to_its_widest_cell() {
+        let array = Array2D::from_slice(&[1, 200, 3, 40, 5, 6], 2, 3);
+        assert_eq!(array.to_string(), " 1 200 3\n40   5 6");
+    }
+
+    #[test]
+    fn formats_cells_with_the_cell_formatter() {
+        let array = Array2D::from_slice(&[1, 2, 3, 4], 2, 2);
+        let render = array.render().cells(|n| n * 10);
+        assert_eq!(render.to_string(), "10 20\n30 40");
+    }
+
+    #[test]
+    fn shows_headers_beside_the_grid() {
+        let array = Array2D::fill('.', 11, 2);
+        let text = array.render().headers(true).to_string();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "   0 1");
+        assert_eq!(lines[1], " 0 . .");
+        assert_eq!(lines[11], "10 . .");
+    }
+
+    #[test]
+    fn clamps_the_viewport_and_notes_the_crop() {
+        let array = Array2D::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3);
+
+        let render = array.render().viewport(1..10, 2..3);
+        assert_eq!(
+            render.to_string(),
+            "6\n9\n(rows 1..3 and columns 2..3 of 3x3)"
+        );
+
+        let render = array.render().viewport(5..10, 0..3);
+        assert_eq!(render.to_string(), "(rows 3..3 and columns 0..3 of 3x3)");
+
+        let render = array.render().viewport(0..3, 0..3);
+        assert_eq!(render.to_string(), "123\n456\n789");
+    }
+
+    #[test]
+    fn debug_shows_the_size_and_crops_large_arrays() {
+        let array = Array2D::from_slice(&["a", "b"], 1, 2);
+        assert_eq!(
+            format!("{array:?}"),
+            "Array2D 1x2\n    0   1\n0 \"a\" \"b\""
+        );
+
+        let large = Array2D::fill(0u8, 100, 70);
+        let debug = format!("{large:?}");
+        let lines: Vec<&str> = debug.lines().collect();
+
+        assert_eq!(lines.len(), 1 + 1 + 64 + 1);
+        assert_eq!(lines[66], "(rows 0..64 and columns 0..64 of 100x70)");
+    }
+
+    #[test]
+    fn debug_of_an_empty_array_is_only_its_size() {
+        let array: Array2D<u8> = Array2D::fill(0, 0, 0);
+
+        assert_eq!(format!("{array:?}"), "Array2D 0x0");
+        assert_eq!(array.to_string(), "");
+    }
+}
@@ -0,0 +1,425 @@
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+
+use super::{Array2D, Array2DIndex};
+
+/// A read-only view of a rectangular region of an [Array2D], created by [Array2D::view]. Indices are relative to the
+/// top left corner of the region, and nothing is copied.
+pub struct ArrayView2D<'a, T> {
+    /// The values from the top left corner of the region to the end of the array.
+    values: &'a [T],
+    /// The distance between the start of each row in [values], which is the number of columns in the array.
+    stride: usize,
+    rows: usize,
+    cols: usize,
+}
+
+impl<'a, T> ArrayView2D<'a, T> {
+    pub(super) fn new(array: &'a Array2D<T>, rows: Range<usize>, cols: Range<usize>) -> Self {
+        let start = (rows.start * array.cols + cols.start).min(array.values.len());
+
+        ArrayView2D {
+            values: &array.values[start..],
+            stride: array.cols,
+            rows: rows.len(),
+            cols: cols.len(),
+        }
+    }
+
+    /// Returns a view of the [rows] and [cols] of this view, relative to its top left corner.
+    ///
+    /// Panics if either range is outside the view.
+    pub fn view(&self, rows: impl RangeBounds<usize>, cols: impl RangeBounds<usize>) -> Self {
+        let rows = range(rows, self.rows);
+        let cols = range(cols, self.cols);
+        let start = (rows.start * self.stride + cols.start).min(self.values.len());
+
+        ArrayView2D {
+            values: &self.values[start..],
+            stride: self.stride,
+            rows: rows.len(),
+            cols: cols.len(),
+        }
+    }
+
+    /// Returns the views of the rows above and from the specified [row].
+    pub fn split_at_row(&self, row: usize) -> (Self, Self) {
+        (self.view(..row, ..), self.view(row.., ..))
+    }
+
+    /// Returns the views of the columns left of and from the specified [col].
+    pub fn split_at_col(&self, col: usize) -> (Self, Self) {
+        (self.view(.., ..col), self.view(.., col..))
+    }
+
+    /// Returns the number of rows in the view.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the view.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns a reference to the value at the specified [row] and [col] of the view, if it exists.
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row < self.rows && col < self.cols {
+            Some(&self.values[row * self.stride + col])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value at the specified [index] of the view, if it exists.
+    pub fn get_tuple(&self, (row, col): Array2DIndex) -> Option<&'a T> {
+        self.get(row, col)
+    }
+
+    /// Returns an iterator over each value in the view, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        self.iter_rows().flatten()
+    }
+
+    /// Returns an iterator over each 2D index in the view, in row-major order.
+    pub fn iter_indices(&self) -> impl Iterator<Item = Array2DIndex> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    /// Returns an iterator over each value in the specified [row] of the view.
+    pub fn iter_row(&self, row: usize) -> impl Iterator<Item = &'a T> + 'a {
+        self.row(row).iter()
+    }
+
+    /// Returns an iterator over each value in the specified [col] of the view.
+    pub fn iter_col(&self, col: usize) -> impl Iterator<Item = &'a T> + 'a {
+        assert!(col < self.cols);
+        let view = *self;
+        (0..self.rows).map(move |row| &view.row(row)[col])
+    }
+
+    /// Returns an iterator over each row in the view.
+    pub fn iter_rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + 'a> + 'a {
+        let view = *self;
+        (0..self.rows).map(move |row| view.iter_row(row))
+    }
+
+    /// Returns an iterator over each column in the view.
+    pub fn iter_cols(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + 'a> + 'a {
+        let view = *self;
+        (0..self.cols).map(move |col| view.iter_col(col))
+    }
+
+    /// Returns an iterator that enumerates each value in the view with its 2D index.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Array2DIndex, &'a T)> + 'a {
+        self.iter_indices().zip(self.iter())
+    }
+
+    /// Returns a new Array2D with the values of the view.
+    pub fn to_array(&self) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        Array2D {
+            values: self.iter().cloned().collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    fn row(self, row: usize) -> &'a [T] {
+        assert!(row < self.rows);
+        &self.values[row * self.stride..][..self.cols]
+    }
+}
+
+// A view is only a slice and its shape, so it can be copied whatever T is, which derived impls would not allow.
+
+impl<T> Clone for ArrayView2D<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ArrayView2D<'_, T> {}
+
+impl<T> Index<Array2DIndex> for ArrayView2D<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Array2DIndex) -> &Self::Output {
+        self.get(row, col).expect("index out of bounds")
+    }
+}
+
+/// A view of a rectangular region of an [Array2D] that allows modifying each value, created by [Array2D::view_mut].
+/// Indices are relative to the top left corner of the region, and nothing is copied.
+///
+/// A view can be split into views of disjoint regions, which can be modified at the same time:
+///
+/// ```ignore
+/// let (mut left, mut right) = array.view_mut(.., ..).split_at_col(5);
+/// left[(0, 0)] = right[(0, 0)];
+/// ```
+pub struct ArrayViewMut2D<'a, T> {
+    /// Each row of the region.
+    values: Vec<&'a mut [T]>,
+    cols: usize,
+}
+
+impl<'a, T> ArrayViewMut2D<'a, T> {
+    pub(super) fn new(array: &'a mut Array2D<T>, rows: Range<usize>, cols: Range<usize>) -> Self {
+        let width = cols.len();
+        let stride = array.cols;
+
+        // Each row is split off the front of the rest of the values, which works even when the rows are empty.
+
+        let mut rest = &mut array.values[rows.start * stride..];
+        let mut values = Vec::with_capacity(rows.len());
+
+        for _ in rows {
+            let (row, after) = std::mem::take(&mut rest).split_at_mut(stride);
+            values.push(&mut row[cols.clone()]);
+            rest = after;
+        }
+
+        ArrayViewMut2D {
+            values,
+            cols: width,
+        }
+    }
+
+    /// Returns a view of the [rows] and [cols] of this view, relative to its top left corner, which borrows this one.
+    ///
+    /// Panics if either range is outside the view.
+    pub fn view_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> ArrayViewMut2D<'_, T> {
+        let rows = range(rows, self.rows());
+        let cols = range(cols, self.cols);
+        let width = cols.len();
+
+        let values = self.values[rows]
+            .iter_mut()
+            .map(|row| &mut row[cols.clone()])
+            .collect();
+
+        ArrayViewMut2D {
+            values,
+            cols: width,
+        }
+    }
+
+    /// Consumes the view, returning the views of the rows above and from the specified [row].
+    pub fn split_at_row(mut self, row: usize) -> (Self, Self) {
+        assert!(row <= self.rows(), "row out of bounds");
+
+        let below = self.values.split_off(row);
+        let cols = self.cols;
+
+        (
+            self,
+            ArrayViewMut2D {
+                values: below,
+                cols,
+            },
+        )
+    }
+
+    /// Consumes the view, returning the views of the columns left of and from the specified [col].
+    pub fn split_at_col(self, col: usize) -> (Self, Self) {
+        assert!(col <= self.cols, "column out of bounds");
+
+        let (left, right) = self
+            .values
+            .into_iter()
+            .map(|row| row.split_at_mut(col))
+            .unzip();
+
+        (
+            ArrayViewMut2D {
+                values: left,
+                cols: col,
+            },
+            ArrayViewMut2D {
+                values: right,
+                cols: self.cols - col,
+            },
+        )
+    }
+
+    /// Returns the number of rows in the view.
+    pub fn rows(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the number of columns in the view.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns a reference to the value at the specified [row] and [col] of the view, if it exists.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.values.get(row)?.get(col)
+    }
+
+    /// Returns a mutable reference to the value at the specified [row] and [col] of the view, if it exists.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.values.get_mut(row)?.get_mut(col)
+    }
+
+    /// Returns a reference to the value at the specified [index] of the view, if it exists.
+    pub fn get_tuple(&self, (row, col): Array2DIndex) -> Option<&T> {
+        self.get(row, col)
+    }
+
+    /// Returns a mutable reference to the value at the specified [index] of the view, if it exists.
+    pub fn get_mut_tuple(&mut self, (row, col): Array2DIndex) -> Option<&mut T> {
+        self.get_mut(row, col)
+    }
+
+    /// Sets the value at the specified [row] and [col] of the view.
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        self[(row, col)] = value;
+    }
+
+    /// Returns an iterator over each value in the view, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.values.iter().flat_map(|row| row.iter())
+    }
+
+    /// Returns an iterator over each value in the view, in row-major order, that allows modifying each value.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + use<'_, 'a, T> {
+        self.values.iter_mut().flat_map(|row| row.iter_mut())
+    }
+
+    /// Returns an iterator over each 2D index in the view, in row-major order.
+    pub fn iter_indices(&self) -> impl Iterator<Item = Array2DIndex> {
+        let cols = self.cols;
+        (0..self.rows()).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    /// Returns an iterator over each value in the specified [row] of the view.
+    pub fn iter_row(&self, row: usize) -> impl Iterator<Item = &T> {
+        self.values[row].iter()
+    }
+
+    /// Returns an iterator over each value in the specified [col] of the view.
+    pub fn iter_col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols);
+        self.values.iter().map(move |row| &row[col])
+    }
+
+    /// Returns an iterator over each row in the view.
+    pub fn iter_rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.rows()).map(|row| self.iter_row(row))
+    }
+
+    /// Returns an iterator over each column in the view.
+    pub fn iter_cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(|col| self.iter_col(col))
+    }
+
+    /// Returns an iterator that enumerates each value in the view with its 2D index.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Array2DIndex, &T)> {
+        self.iter_indices().zip(self.iter())
+    }
+
+    /// Returns an iterator that enumerates each value in the view with its 2D index, that allows modifying each value.
+    pub fn enumerate_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (Array2DIndex, &mut T)> + use<'_, 'a, T> {
+        self.iter_indices().zip(self.iter_mut())
+    }
+
+    /// Returns a new Array2D with the values of the view.
+    pub fn to_array(&self) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        Array2D {
+            values: self.iter().cloned().collect(),
+            rows: self.rows(),
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Index<Array2DIndex> for ArrayViewMut2D<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Array2DIndex) -> &Self::Output {
+        self.get(row, col).expect("index out of bounds")
+    }
+}
+
+impl<T> IndexMut<Array2DIndex> for ArrayViewMut2D<'_, T> {
+    fn index_mut(&mut self, (row, col): Array2DIndex) -> &mut Self::Output {
+        self.get_mut(row, col).expect("index out of bounds")
+    }
+}
+
+/// Returns the [bounds] as a range of indices less than [len].
+///
+/// Panics if the range is outside `0..len`.
+pub(super) fn range(bounds: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match bounds.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match bounds.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end && end <= len,
+        "range {start}..{end} out of bounds for length {len}"
+    );
+
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn views_of_empty_rows_keep_their_row_count() {
+        let mut array: Array2D<u8> = Array2D::new(3, 0);
+
+        assert_eq!(array.view(.., ..).rows(), 3);
+        assert_eq!(array.view_mut(.., ..).rows(), 3);
+        assert_eq!(array.view_mut(1.., ..).rows(), 2);
+    }
+
+    #[test]
+    fn splits_into_disjoint_mutable_views() {
+        let mut array = Array2D::from_slice(&[1, 2, 3, 4, 5, 6], 2, 3);
+
+        let (left, mut right) = array.view_mut(.., ..).split_at_col(1);
+        let (mut top, mut bottom) = left.split_at_row(1);
+
+        top[(0, 0)] = 10;
+        bottom[(0, 0)] = 40;
+        right.set(1, 1, 60);
+
+        assert_eq!(
+            array.iter().copied().collect::<Vec<_>>(),
+            vec![10, 2, 3, 40, 5, 60]
+        );
+        assert_eq!(
+            array
+                .view(1.., 1..)
+                .to_array()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![5, 60]
+        );
+    }
+}
@@ -2,9 +2,11 @@ use itertools::Itertools;
 
 use aoc::aoc;
 
-aoc!(part_one, part_two);
+aoc!("Sonar Sweep", parse_input => part_one, part_two);
 
-fn parse_input(input: &str) -> Vec<u32> {
+type Depths = Vec<u32>;
+
+fn parse_input(input: &str) -> Depths {
     input.lines().map(parse_input_line).collect()
 }
 
@@ -12,9 +14,7 @@ fn parse_input_line(line: &str) -> u32 {
     line.parse().unwrap()
 }
 
-fn part_one(input: &str) -> u32 {
-    let values = parse_input(input);
-
+fn part_one(values: &Depths) -> u32 {
     let mut result = 0;
 
     for (a, b) in values.iter().tuple_windows() {
@@ -26,9 +26,7 @@ fn part_one(input: &str) -> u32 {
     result
 }
 
-fn part_two(input: &str) -> u32 {
-    let values = parse_input(input);
-
+fn part_two(values: &Depths) -> u32 {
     let mut result = 0;
 
     for (a, b, c, d) in values.iter().tuple_windows() {
@@ -1,34 +1,24 @@
-use aoc::aoc;
+use aoc::{aoc, AocParse, Result};
 
-aoc!(part_one, part_two);
+aoc!("Dive!", parse_input => part_one, part_two);
 
+#[derive(AocParse)]
 enum Command {
+    #[aoc(fmt = "forward {0}")]
     F(i32),
+    #[aoc(fmt = "down {0}")]
     D(i32),
+    #[aoc(fmt = "up {0}")]
     U(i32),
 }
 
-fn parse_input(input: &str) -> Vec<Command> {
-    input.lines().map(parse_input_line).collect()
-}
-
-fn parse_input_line(line: &str) -> Command {
-    let mut parts = line.split_whitespace();
-
-    let dir = parts.next().unwrap();
-    let val = parts.next().unwrap().parse().unwrap();
+type Commands = Vec<Command>;
 
-    match dir {
-        "forward" => Command::F(val),
-        "down" => Command::D(val),
-        "up" => Command::U(val),
-        _ => panic!(),
-    }
+fn parse_input(input: &str) -> Result<Commands> {
+    input.lines().map(str::parse).collect()
 }
 
-fn part_one(input: &str) -> i32 {
-    let commands = parse_input(input);
-
+fn part_one(commands: &Commands) -> i32 {
     let mut position = 0;
     let mut depth = 0;
 
@@ -43,9 +33,7 @@ fn part_one(input: &str) -> i32 {
     position * depth
 }
 
-fn part_two(input: &str) -> i32 {
-    let commands = parse_input(input);
-
+fn part_two(commands: &Commands) -> i32 {
     let mut position = 0;
     let mut depth = 0;
     let mut aim = 0;
@@ -2,22 +2,35 @@ use itertools::Itertools;
 
 use aoc::aoc;
 
-aoc!(part_one, part_two);
+aoc!("Binary Diagnostic", parse_input => part_one, part_two);
 
-const WIDTH: usize = 12;
+struct Report {
+    /// The values of the report, in ascending order.
+    values: Vec<u32>,
+    /// The number of bits in each value.
+    width: usize,
+}
+
+fn parse_input(input: &str) -> Report {
+    let values = input.lines().map(parse_input_line).sorted().collect();
+    let width = parse_width(input);
 
-fn parse_input(input: &str) -> Vec<u32> {
-    input.lines().map(parse_input_line).sorted().collect()
+    Report { values, width }
+}
+
+/// Returns the number of bits in each value of the [input].
+fn parse_width(input: &str) -> usize {
+    input.lines().next().map_or(0, str::len)
 }
 
 fn parse_input_line(line: &str) -> u32 {
     u32::from_str_radix(line, 2).unwrap()
 }
 
-fn part_one(input: &str) -> u32 {
-    let values = parse_input(input);
+fn part_one(report: &Report) -> u32 {
+    let values = &report.values;
 
-    let mut counts = [0u32; WIDTH];
+    let mut counts = vec![0u32; report.width];
 
     for (bit, count) in counts.iter_mut().enumerate() {
         for value in values.iter() {
@@ -43,19 +56,17 @@ fn part_one(input: &str) -> u32 {
     rate_gam * rate_eps
 }
 
-fn part_two(input: &str) -> u32 {
-    let values = parse_input(input);
-
-    let rate_oxy = part_two_search(&values, true);
-    let rate_co2 = part_two_search(&values, false);
+fn part_two(report: &Report) -> u32 {
+    let rate_oxy = part_two_search(&report.values, report.width, true);
+    let rate_co2 = part_two_search(&report.values, report.width, false);
 
     rate_oxy * rate_co2
 }
 
-fn part_two_search(values: &[u32], use_larger: bool) -> u32 {
+fn part_two_search(values: &[u32], width: usize, use_larger: bool) -> u32 {
     let mut values = values;
 
-    for bit in (0..WIDTH).rev() {
+    for bit in (0..width).rev() {
         let i = values.iter().position(|&v| read_bit(v, bit)).unwrap_or(0);
 
         let mut group_a = &values[..i];
@@ -1,12 +1,13 @@
 use std::iter::zip;
 
-use aoc::aoc;
 use aoc::array::Array2D;
+use aoc::{aoc, paragraphs, parse_list, parse_list_by, Error, Result};
 
-aoc!(part_one, part_two);
+aoc!("Giant Squid", parse_input => part_one, part_two);
 
 const WIDTH: usize = 5;
 
+#[derive(Clone)]
 struct Board {
     values: Array2D<u32>,
     picked: Array2D<bool>,
@@ -34,21 +35,25 @@ impl Board {
     }
 }
 
-fn parse_input(input: &str) -> (Vec<u32>, Vec<Board>) {
-    let mut lines = input.lines();
+type Game = (Vec<u32>, Vec<Board>);
 
-    let numbers = lines.next().unwrap();
-    let numbers = parse_selected_numbers(numbers);
+fn parse_input(input: &str) -> Result<Game> {
+    let mut paragraphs = paragraphs(input);
+
+    let numbers = paragraphs
+        .next()
+        .ok_or_else(|| Error::new("expected a line of numbers"))?;
+    let numbers = parse_list_by(numbers, ",")?;
 
     let mut boards = vec![];
 
-    while lines.next().is_some() {
-        let mut values = vec![];
+    for board in paragraphs {
+        let values: Vec<u32> = parse_list(board)?;
 
-        for _ in 0..WIDTH {
-            let next_values = lines.next().unwrap();
-            let next_values = parse_board_line(next_values);
-            values.extend(next_values);
+        if values.len() != WIDTH * WIDTH {
+            return Err(
+                Error::new(format!("expected a board of {WIDTH}x{WIDTH} numbers")).at(board),
+            );
         }
 
         let values = Array2D::from_slice(&values, WIDTH, WIDTH);
@@ -57,38 +62,30 @@ fn parse_input(input: &str) -> (Vec<u32>, Vec<Board>) {
         boards.push(Board { values, picked });
     }
 
-    (numbers, boards)
-}
-
-fn parse_selected_numbers(str: &str) -> Vec<u32> {
-    str.split(',').flat_map(str::parse).collect()
-}
-
-fn parse_board_line(str: &str) -> Vec<u32> {
-    str.split_whitespace().flat_map(str::parse).collect()
+    Ok((numbers, boards))
 }
 
-fn part_one(input: &str) -> u32 {
-    let (numbers, mut boards) = parse_input(input);
+fn part_one((numbers, boards): &Game) -> Result<u32> {
+    let mut boards = boards.clone();
 
-    for n in numbers {
+    for &n in numbers {
         for b in boards.iter_mut() {
             if b.pick(n) {
-                return sum_unpicked_board_values(b) * n;
+                return Ok(sum_unpicked_board_values(b) * n);
             }
         }
     }
 
-    panic!("no matching board");
+    Err(Error::new("no matching board"))
 }
 
-fn part_two(input: &str) -> u32 {
-    let (numbers, mut boards) = parse_input(input);
+fn part_two((numbers, boards): &Game) -> Result<u32> {
+    let mut boards = boards.clone();
 
     let mut done_state = vec![false; boards.len()];
     let mut done_count = 0;
 
-    for n in numbers {
+    for &n in numbers {
         for (i, b) in boards.iter_mut().enumerate() {
             if done_state[i] {
                 continue;
@@ -103,12 +100,12 @@ fn part_two(input: &str) -> u32 {
             done_count += 1;
 
             if done_count == done_state.len() {
-                return sum_unpicked_board_values(b) * n;
+                return Ok(sum_unpicked_board_values(b) * n);
             }
         }
     }
 
-    panic!("no matching board");
+    Err(Error::new("no matching board"))
 }
 
 fn sum_unpicked_board_values(board: &Board) -> u32 {
@@ -1,13 +1,14 @@
 use std::cmp::Ordering;
 
-use aoc::aoc;
 use aoc::array::Array2D;
+use aoc::{aoc, AocParse, Result};
 
-aoc!(part_one, part_two);
+aoc!("Hydrothermal Venture", parse_input => part_one, part_two);
 
 const WIDTH: usize = 1000;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, AocParse)]
+#[aoc(fmt = "{ax},{ay} -> {bx},{by}")]
 struct Line {
     ax: usize,
     ay: usize,
@@ -15,30 +16,18 @@ struct Line {
     by: usize,
 }
 
-fn parse_input(input: &str) -> Vec<Line> {
-    input.lines().map(parse_input_line).collect()
-}
-
-fn parse_input_line(line: &str) -> Line {
-    let mut parts = line.split(" -> ");
-
-    let mut a = parts.next().unwrap().split(',');
-    let mut b = parts.next().unwrap().split(',');
-
-    let ax = a.next().unwrap().parse().unwrap();
-    let ay = a.next().unwrap().parse().unwrap();
-    let bx = b.next().unwrap().parse().unwrap();
-    let by = b.next().unwrap().parse().unwrap();
+type Lines = Vec<Line>;
 
-    Line { ax, ay, bx, by }
+fn parse_input(input: &str) -> Result<Lines> {
+    input.lines().map(str::parse).collect()
 }
 
-fn part_one(input: &str) -> u32 {
-    solve(&parse_input(input), true)
+fn part_one(lines: &Lines) -> u32 {
+    solve(lines, true)
 }
 
-fn part_two(input: &str) -> u32 {
-    solve(&parse_input(input), false)
+fn part_two(lines: &Lines) -> u32 {
+    solve(lines, false)
 }
 
 fn solve(lines: &[Line], filter_orthogonal: bool) -> u32 {
@@ -1,19 +1,21 @@
-use aoc::aoc;
+use aoc::{aoc, parse_list_by, Result};
 
-aoc!(part_one, part_two);
+aoc!("Lanternfish", parse_input => part_one, part_two);
 
 const MAX_LIFETIME: usize = 9;
 
-fn parse_input(input: &str) -> Vec<usize> {
-    input.split(',').flat_map(str::parse).collect()
+type School = Vec<usize>;
+
+fn parse_input(input: &str) -> Result<School> {
+    parse_list_by(input, ",")
 }
 
-fn part_one(input: &str) -> u64 {
-    solve(&parse_input(input), 80)
+fn part_one(lifetimes: &School) -> u64 {
+    solve(lifetimes, 80)
 }
 
-fn part_two(input: &str) -> u64 {
-    solve(&parse_input(input), 256)
+fn part_two(lifetimes: &School) -> u64 {
+    solve(lifetimes, 256)
 }
 
 fn solve(lifetimes: &[usize], days: u32) -> u64 {
@@ -1,19 +1,21 @@
 use itertools::Itertools;
 
-use aoc::aoc;
+use aoc::{aoc, parse_list_by, Result};
 
-aoc!(part_one, part_two);
+aoc!("The Treachery of Whales", parse_input => part_one, part_two);
 
-fn parse_input(input: &str) -> Vec<u32> {
-    input.split(',').flat_map(str::parse).collect()
+type Positions = Vec<u32>;
+
+fn parse_input(input: &str) -> Result<Positions> {
+    parse_list_by(input, ",")
 }
 
-fn part_one(input: &str) -> u32 {
-    solve(&parse_input(input), simple_cost)
+fn part_one(positions: &Positions) -> u32 {
+    solve(positions, simple_cost)
 }
 
-fn part_two(input: &str) -> u32 {
-    solve(&parse_input(input), complex_cost)
+fn part_two(positions: &Positions) -> u32 {
+    solve(positions, complex_cost)
 }
 
 fn simple_cost(dist: u32) -> u32 {
@@ -1,13 +1,15 @@
 use aoc::aoc;
 
-aoc!(part_one, part_two);
+aoc!("Seven Segment Search", parse_input => part_one, part_two);
 
 struct ProblemValue {
     signals: Vec<u8>,
     outputs: Vec<u8>,
 }
 
-fn parse_input(input: &str) -> Vec<ProblemValue> {
+type Problem = Vec<ProblemValue>;
+
+fn parse_input(input: &str) -> Problem {
     input.lines().map(parse_input_line).collect()
 }
 
@@ -43,9 +45,7 @@ fn parse_input_signal(signal: &str) -> u8 {
     bits
 }
 
-fn part_one(input: &str) -> u32 {
-    let values = parse_input(input);
-
+fn part_one(values: &Problem) -> u32 {
     let mut result = 0u32;
 
     for v in values {
@@ -63,9 +63,7 @@ fn part_one(input: &str) -> u32 {
     result
 }
 
-fn part_two(input: &str) -> u32 {
-    let values = parse_input(input);
-
+fn part_two(values: &Problem) -> u32 {
     let mut result = 0u32;
 
     for v in values {
@@ -1,32 +1,20 @@
-use aoc::aoc;
-use aoc::array::Array2D;
+use aoc::array::{Array2D, Array2DIndex, Neighborhood};
+use aoc::{aoc, Result};
 use std::collections::VecDeque;
 
-aoc!(part_one, part_two);
-
-const WIDTH: usize = 100;
+aoc!("Smoke Basin", parse_input => part_one, part_two);
 
 type HeightMap = Array2D<u32>;
 
-fn parse_input(input: &str) -> HeightMap {
-    let chars = input.lines().flat_map(str::chars);
-
-    let values: Vec<u32> = chars.map(parse_input_value).collect();
-
-    Array2D::from_slice(&values, WIDTH, WIDTH)
+fn parse_input(input: &str) -> Result<HeightMap> {
+    Array2D::from_grid_with(input, |c| c.to_digit(10))
 }
 
-fn parse_input_value(c: char) -> u32 {
-    c.to_digit(10).unwrap()
-}
-
-fn part_one(input: &str) -> u32 {
-    let heights = parse_input(input);
-
+fn part_one(heights: &HeightMap) -> u32 {
     let mut result = 0;
 
-    for ((row, col), &value) in heights.enumerate() {
-        if is_minimum(&heights, col, row) {
+    for (i, &value) in heights.enumerate() {
+        if is_minimum(heights, i) {
             result += 1 + value;
         }
     }
@@ -34,14 +22,12 @@ fn part_one(input: &str) -> u32 {
     result
 }
 
-fn part_two(input: &str) -> u32 {
-    let heights = parse_input(input);
-
+fn part_two(heights: &HeightMap) -> u32 {
     let mut basin_sizes = vec![];
 
-    for (row, col) in heights.iter_indices() {
-        if is_minimum(&heights, col, row) {
-            basin_sizes.push(find_basin_size(&heights, col, row));
+    for i in heights.iter_indices() {
+        if is_minimum(heights, i) {
+            basin_sizes.push(find_basin_size(heights, i));
         }
     }
 
@@ -51,67 +37,38 @@ fn part_two(input: &str) -> u32 {
     basin_sizes.iter().rev().take(3).product()
 }
 
-fn is_minimum(heights: &HeightMap, x: usize, y: usize) -> bool {
-    let v = heights.get(y, x);
-
-    if x > 0 && v >= heights.get(y, x - 1) {
-        return false;
-    }
-
-    if y > 0 && v >= heights.get(y - 1, x) {
-        return false;
-    }
-
-    if x < heights.cols() - 1 && v >= heights.get(y, x + 1) {
-        return false;
-    }
-
-    if y < heights.rows() - 1 && v >= heights.get(y + 1, x) {
-        return false;
-    }
+fn is_minimum(heights: &HeightMap, i: Array2DIndex) -> bool {
+    let v = heights[i];
 
-    true
+    heights
+        .neighbors(i, &Neighborhood::VON_NEUMANN)
+        .all(|(_, &neighbor)| v < neighbor)
 }
 
-fn find_basin_size(heights: &HeightMap, x: usize, y: usize) -> u32 {
-    let (min_x, max_x) = (0, heights.cols() - 1);
-    let (min_y, max_y) = (0, heights.rows() - 1);
-
+fn find_basin_size(heights: &HeightMap, i: Array2DIndex) -> u32 {
     let mut seen = Array2D::fill(false, heights.rows(), heights.cols());
     let mut size = 0;
 
     let mut queue = VecDeque::new();
 
-    queue.push_back((x, y));
+    queue.push_back(i);
 
-    while let Some((x, y)) = queue.pop_front() {
-        if seen[(y, x)] {
+    while let Some(i) = queue.pop_front() {
+        if seen[i] {
             continue;
         }
 
-        if heights[(y, x)] >= 9 {
+        if heights[i] >= 9 {
             continue;
         }
 
         size += 1; // the coordinate is part of the basin
 
-        if x > min_x {
-            queue.push_back((x - 1, y));
-        }
-
-        if y > min_y {
-            queue.push_back((x, y - 1));
-        }
-
-        if x < max_x {
-            queue.push_back((x + 1, y));
-        }
-
-        if y < max_y {
-            queue.push_back((x, y + 1));
+        for (neighbor, _) in heights.neighbors(i, &Neighborhood::VON_NEUMANN) {
+            queue.push_back(neighbor);
         }
 
-        seen.set(y, x, true);
+        seen[i] = true;
     }
 
     size
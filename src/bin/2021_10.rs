@@ -1,8 +1,8 @@
 use aoc::aoc;
 
-use crate::ChunkDelimiter::*;
+use self::ChunkDelimiter::*;
 
-aoc!(part_one, part_two);
+aoc!("Syntax Scoring", parse_input => part_one, part_two);
 
 #[derive(Copy, Clone, PartialEq)]
 enum ChunkDelimiter {
@@ -18,7 +18,9 @@ enum ChunkDelimiterSymbol {
     D,
 }
 
-fn parse_input(input: &str) -> Vec<Vec<ChunkDelimiter>> {
+type Lines = Vec<Vec<ChunkDelimiter>>;
+
+fn parse_input(input: &str) -> Lines {
     input.lines().map(parse_input_line).collect()
 }
 
@@ -40,11 +42,11 @@ fn parse_input_char(char: char) -> ChunkDelimiter {
     }
 }
 
-fn part_one(input: &str) -> u64 {
+fn part_one(lines: &Lines) -> u64 {
     let mut scores = vec![];
 
-    for line in parse_input(input) {
-        if let (false, s) = solve(&line) {
+    for line in lines {
+        if let (false, s) = solve(line) {
             scores.push(s);
         }
     }
@@ -52,11 +54,11 @@ fn part_one(input: &str) -> u64 {
     scores.iter().sum()
 }
 
-fn part_two(input: &str) -> u64 {
+fn part_two(lines: &Lines) -> u64 {
     let mut scores = vec![];
 
-    for line in parse_input(input) {
-        if let (true, s) = solve(&line) {
+    for line in lines {
+        if let (true, s) = solve(line) {
             scores.push(s);
         }
     }
@@ -1,6 +1,6 @@
 use aoc::aoc;
 
-aoc!(part_one, part_two);
+aoc!("Trebuchet?!", part_one, part_two);
 
 fn part_one(input: &str) -> u32 {
     input.lines().map(solve_without_alpha).sum()
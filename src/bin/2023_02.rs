@@ -1,7 +1,7 @@
-use aoc::aoc;
-use aoc::select;
+use aoc::combinator::{alt, map, number, pair, parse_all, sep_by1, tag, terminated, Parser};
+use aoc::{aoc, scan, select, Result};
 
-aoc!(part_one, part_two);
+aoc!("Cube Conundrum", parse_input => part_one, part_two);
 
 struct Game {
     index: u32,
@@ -10,69 +10,60 @@ struct Game {
 
 type GameDraw = [u32; 3];
 
-fn part_one(input: &str) -> u32 {
-    parse_input(input).iter().map(part_one_each).sum()
+type Games = Vec<Game>;
+
+fn part_one(games: &Games) -> u32 {
+    games.iter().map(part_one_each).sum()
 }
 
 fn part_one_each(game: &Game) -> u32 {
     select(valid_game(game), game.index, 0)
 }
 
-fn part_two(input: &str) -> u32 {
-    parse_input(input).iter().map(part_two_each).sum()
+fn part_two(games: &Games) -> u32 {
+    games.iter().map(part_two_each).sum()
 }
 
 fn part_two_each(game: &Game) -> u32 {
     minimum_cubes(game).iter().product()
 }
 
-fn parse_input(input: &str) -> Vec<Game> {
-    input.lines().map(parse_input_line).collect()
-}
+fn parse_input(input: &str) -> Result<Games> {
+    let parser = sep_by1(parse_input_draw(), tag("; "));
 
-fn parse_input_line(s: &str) -> Game {
-    let mut parts = s.split(": ");
-
-    let index = parts
-        .next()
-        .unwrap()
-        .strip_prefix("Game ")
-        .map(str::parse)
-        .unwrap()
-        .unwrap();
-
-    let draws = parts
-        .next()
-        .unwrap()
-        .split("; ")
-        .map(parse_input_draw)
-        .collect();
-
-    Game { index, draws }
+    input
+        .lines()
+        .map(|line| parse_input_line(line, &parser))
+        .collect()
 }
 
-fn parse_input_draw(s: &str) -> GameDraw {
-    let mut result = [0; 3];
-
-    for (count, color) in s.split(", ").map(parse_input_cube) {
-        result[color] = count;
-    }
+fn parse_input_line<'a>(s: &'a str, parser: &impl Parser<'a, Vec<GameDraw>>) -> Result<Game> {
+    let (index, draws) = scan!("Game {}: {}", s => u32, &str)?;
+    let draws = parse_all(parser, draws)?;
 
-    result
+    Ok(Game { index, draws })
 }
 
-fn parse_input_cube(s: &str) -> (u32, usize) {
-    let mut parts = s.split(' ');
+fn parse_input_draw<'a>() -> impl Parser<'a, GameDraw> {
+    let cube = pair(terminated(number::<u32>(), tag(" ")), parse_input_color());
 
-    let count = parts.next().unwrap().parse().unwrap();
-    let color = parts.next().unwrap();
+    map(sep_by1(cube, tag(", ")), |cubes| {
+        let mut result = [0; 3];
 
-    match color.chars().next() {
-        Some('r') => (count, 0),
-        Some('g') => (count, 1),
-        Some('b') => (count, 2),
-        _ => panic!(),
-    }
+        for (count, color) in cubes {
+            result[color] = count;
+        }
+
+        result
+    })
+}
+
+fn parse_input_color<'a>() -> impl Parser<'a, usize> {
+    alt((
+        map(tag("red"), |_| 0),
+        map(tag("green"), |_| 1),
+        map(tag("blue"), |_| 2),
+    ))
 }
 
 fn valid_game(game: &Game) -> bool {
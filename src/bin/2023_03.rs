@@ -1,10 +1,8 @@
-use aoc::aoc;
 use aoc::array::{Array2D, Array2DIndex};
+use aoc::{aoc, Result};
 use std::collections::HashSet;
 
-aoc!(part_one, part_two);
-
-const WIDTH: usize = 140;
+aoc!("Gear Ratios", parse_input => part_one, part_two);
 
 #[derive(Copy, Clone, PartialEq)]
 enum SchematicValue {
@@ -18,14 +16,12 @@ struct Schematic {
     numbers: Vec<u32>,
 }
 
-fn part_one(input: &str) -> u32 {
-    let schematic = parse_input(input);
-
+fn part_one(schematic: &Schematic) -> u32 {
     let mut symbol_part_sets = vec![];
 
     for (i, &value) in schematic.grid.enumerate() {
         if let SchematicValue::Symbol(_) = value {
-            symbol_part_sets.push(collect_part_set(&schematic, i));
+            symbol_part_sets.push(collect_part_set(schematic, i));
         }
     }
 
@@ -38,14 +34,12 @@ fn part_one(input: &str) -> u32 {
     result
 }
 
-fn part_two(input: &str) -> u32 {
-    let schematic = parse_input(input);
-
+fn part_two(schematic: &Schematic) -> u32 {
     let mut symbol_part_sets = vec![];
 
     for (i, &value) in schematic.grid.enumerate() {
         if value == SchematicValue::Symbol('*') {
-            symbol_part_sets.push(collect_part_set(&schematic, i));
+            symbol_part_sets.push(collect_part_set(schematic, i));
         }
     }
 
@@ -73,14 +67,16 @@ fn collect_part_set(schematic: &Schematic, (row, col): Array2DIndex) -> HashSet<
     result
 }
 
-fn parse_input(input: &str) -> Schematic {
-    let mut grid = Array2D::fill(SchematicValue::None, WIDTH, WIDTH);
+fn parse_input(input: &str) -> Result<Schematic> {
+    let chars: Array2D<char> = input.parse()?;
+
+    let mut grid = Array2D::fill(SchematicValue::None, chars.rows(), chars.cols());
 
     let mut numbers = vec![];
     let mut numbers_index = None;
 
-    for (row, line) in input.lines().enumerate() {
-        for (col, char) in line.chars().enumerate() {
+    for (row, line) in chars.iter_rows().enumerate() {
+        for (col, &char) in line.enumerate() {
             let cell;
 
             if char.to_digit(10).is_none() {
@@ -110,5 +106,5 @@ fn parse_input(input: &str) -> Schematic {
         numbers_index = None; // terminate part number at end of row
     }
 
-    Schematic { grid, numbers }
+    Ok(Schematic { grid, numbers })
 }
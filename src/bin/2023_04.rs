@@ -1,10 +1,13 @@
-use aoc::aoc;
+use aoc::{aoc, ints, Error, Result};
 use std::collections::HashSet;
 
-aoc!(part_one, part_two);
+aoc!("Scratchcards", parse_input => part_one, part_two);
 
-fn part_one(input: &str) -> u32 {
-    parse_input(input).iter().map(part_one_each).sum()
+/// The number of winning numbers on each card.
+type Cards = Vec<u32>;
+
+fn part_one(cards: &Cards) -> u32 {
+    cards.iter().map(part_one_each).sum()
 }
 
 fn part_one_each(score: &u32) -> u32 {
@@ -14,9 +17,7 @@ fn part_one_each(score: &u32) -> u32 {
     }
 }
 
-fn part_two(input: &str) -> u32 {
-    let cards = parse_input(input);
-
+fn part_two(cards: &Cards) -> u32 {
     let mut counts = vec![1u32; cards.len()];
 
     for (i, &score) in cards.iter().enumerate() {
@@ -28,19 +29,18 @@ fn part_two(input: &str) -> u32 {
     counts.iter().sum()
 }
 
-fn parse_input(input: &str) -> Vec<u32> {
+fn parse_input(input: &str) -> Result<Cards> {
     input.lines().map(parse_input_line).collect()
 }
 
-fn parse_input_line(s: &str) -> u32 {
-    let mut parts = s.split(':').nth(1).unwrap().split('|');
+fn parse_input_line(s: &str) -> Result<u32> {
+    let (winners, numbers) = s
+        .split_once(':')
+        .and_then(|(_, numbers)| numbers.split_once('|'))
+        .ok_or_else(|| Error::new("expected `Card <index>: <winners> | <numbers>`").at(s))?;
 
-    let winners = parse_input_numbers(parts.next().unwrap().trim());
-    let numbers = parse_input_numbers(parts.next().unwrap().trim());
-
-    HashSet::intersection(&winners, &numbers).count() as u32
-}
+    let winners: HashSet<u32> = ints(winners).collect::<Result<_>>()?;
+    let numbers: HashSet<u32> = ints(numbers).collect::<Result<_>>()?;
 
-fn parse_input_numbers(s: &str) -> HashSet<u32> {
-    HashSet::from_iter(s.split_whitespace().flat_map(str::parse))
+    Ok(HashSet::intersection(&winners, &numbers).count() as u32)
 }
@@ -1,11 +1,10 @@
 use aoc::*;
 use std::cmp::Ordering;
 use std::collections::HashSet;
-use std::iter::Map;
 
 use itertools::Itertools;
 
-aoc!(part_one, part_two);
+aoc!("If You Give A Seed A Fertilizer", parse_input => part_one, part_two);
 
 /// A mapping from the [src] range to the [dst] range.
 #[derive(Debug, Copy, Clone)]
@@ -21,6 +20,9 @@ struct Range {
     upper: u64,
 }
 
+/// The seeds, and the mappings of each category of the almanac in order.
+type Almanac = (Vec<u64>, Vec<Vec<Mapping>>);
+
 impl Mapping {
     /// Returns the identity mapping with the [lower] and [upper] bounds.
     fn identity(lower: u64, upper: u64) -> Self {
@@ -59,21 +61,21 @@ impl Range {
     }
 }
 
-fn part_one(input: &str) -> u64 {
-    let (seed_values, almanac) = parse_input(input);
-
+fn part_one((seed_values, almanac): &Almanac) -> Result<u64> {
     let mut results = vec![];
 
     for seed in seed_values.iter().cloned() {
-        results.push(map_seed(seed, &almanac))
+        results.push(map_seed(seed, almanac))
     }
 
-    results.iter().cloned().min().unwrap()
+    results
+        .iter()
+        .cloned()
+        .min()
+        .ok_or_else(|| Error::new("no seeds"))
 }
 
-fn part_two(input: &str) -> u64 {
-    let (seed_ranges, almanac) = parse_input(input);
-
+fn part_two((seed_ranges, almanac): &Almanac) -> Result<u64> {
     let mut seed_subset = HashSet::new();
 
     seed_subset.insert(0);
@@ -98,18 +100,22 @@ fn part_two(input: &str) -> u64 {
 
     let mut results = vec![];
 
-    let seed_ranges = collect_seed_ranges(&seed_ranges);
+    let seed_ranges = collect_seed_ranges(seed_ranges);
 
     for &s in &seed_subset {
         for &r in &seed_ranges {
             if r.contains(s) {
-                results.push(map_seed(s, &almanac));
+                results.push(map_seed(s, almanac));
                 break;
             }
         }
     }
 
-    results.iter().cloned().min().unwrap()
+    results
+        .iter()
+        .cloned()
+        .min()
+        .ok_or_else(|| Error::new("no seeds"))
 }
 
 /// Map a seed value through the full list of almanac categories.
@@ -137,55 +143,39 @@ fn collect_seed_ranges(seeds: &[u64]) -> Vec<Range> {
     result
 }
 
-fn parse_input(input: &str) -> (Vec<u64>, Vec<Vec<Mapping>>) {
-    let mut lines = input.lines();
-
-    let seeds = parse_u64_list(lines.next().unwrap());
-
-    let lines = lines.collect_vec();
-
-    // Collect the indices of the title lines.
-
-    let mut category_title_lines = vec![];
-
-    for (i, line) in lines.iter().enumerate() {
-        if line.contains(':') {
-            category_title_lines.push(i);
-        }
-    }
-
-    // Append the eof line (plus one because all the others are followed by a blank line)
-
-    category_title_lines.push(lines.len() + 1);
-
-    // Use tuple windows to find the range of lines of each category.
-
-    let mut categories = vec![];
+fn parse_input(input: &str) -> Result<Almanac> {
+    let mut sections = sections(input);
 
-    for (a, b) in category_title_lines.iter().tuple_windows() {
-        let a = a.saturating_add(1); // skip title line
-        let b = b.saturating_sub(1); // skip blank line
+    let seeds = sections
+        .next()
+        .and_then(|s| s.header.strip_prefix("seeds:"))
+        .ok_or_else(|| Error::new("expected a line of seeds"))?;
+    let seeds = parse_list(seeds)?;
 
-        let lines = &lines[a..b];
+    let categories = sections.map(parse_input_category).collect::<Result<_>>()?;
 
-        let mut mappings = lines.iter().cloned().map(parse_input_range).collect();
+    Ok((seeds, categories))
+}
 
-        fill_mapping_gaps(&mut mappings);
+fn parse_input_category(section: Section) -> Result<Vec<Mapping>> {
+    let mut mappings = section
+        .body
+        .lines()
+        .map(parse_input_range)
+        .collect::<Result<_>>()?;
 
-        categories.push(mappings)
-    }
+    fill_mapping_gaps(&mut mappings);
 
-    (seeds, categories)
+    Ok(mappings)
 }
 
-fn parse_input_range(s: &str) -> Mapping {
-    match parse_u64_list(s)[0..3] {
-        [dst_start, src_start, len] => Mapping {
-            src: Range::new(src_start, src_start + len),
-            dst: Range::new(dst_start, dst_start + len),
-        },
-        _ => panic!("invalid input range"),
-    }
+fn parse_input_range(s: &str) -> Result<Mapping> {
+    let [dst_start, src_start, len] = parse_array(s)?;
+
+    Ok(Mapping {
+        src: Range::new(src_start, src_start + len),
+        dst: Range::new(dst_start, dst_start + len),
+    })
 }
 
 /// Fill gaps in the range mappings with the identity mapping to reduce special cases.
@@ -1,20 +1,22 @@
-use aoc::{aoc, parse_u64_list};
+use aoc::{aoc, ints, Error, Result};
 use itertools::Itertools;
 use std::iter::zip;
 
-aoc!(part_one, part_two);
+aoc!("Wait For It", parse_input => part_one, part_two);
 
 struct Race {
     time: u64,
     dist: u64,
 }
 
-fn part_one(input: &str) -> u64 {
-    parse_input(input).iter().map(solve).product()
+type Races = Vec<Race>;
+
+fn part_one(races: &Races) -> u64 {
+    races.iter().map(solve).product()
 }
 
-fn part_two(input: &str) -> u64 {
-    solve(&merge_races(&parse_input(input)))
+fn part_two(races: &Races) -> u64 {
+    solve(&merge_races(races))
 }
 
 /// Returns the number of winning charge times for a given [race].
@@ -58,18 +60,24 @@ fn merge_races(races: &[Race]) -> Race {
     merged
 }
 
-fn parse_input(input: &str) -> Vec<Race> {
+fn parse_input(input: &str) -> Result<Races> {
     let mut lines = input.lines();
 
-    let times = lines.next().unwrap().strip_prefix("Time:").unwrap();
-    let dists = lines.next().unwrap().strip_prefix("Distance:").unwrap();
+    let times = lines
+        .next()
+        .and_then(|s| s.strip_prefix("Time:"))
+        .ok_or_else(|| Error::new("expected a line of times"))?;
+    let dists = lines
+        .next()
+        .and_then(|s| s.strip_prefix("Distance:"))
+        .ok_or_else(|| Error::new("expected a line of distances"))?;
 
-    let times = parse_u64_list(times);
-    let dists = parse_u64_list(dists);
+    let times = ints::<u64>(times).collect::<Result<Vec<_>>>()?;
+    let dists = ints::<u64>(dists).collect::<Result<Vec<_>>>()?;
 
     fn map_into_race((time, dist): (u64, u64)) -> Race {
         Race { time, dist }
     }
 
-    zip(times, dists).map(map_into_race).collect_vec()
+    Ok(zip(times, dists).map(map_into_race).collect_vec())
 }
@@ -2,7 +2,7 @@ use aoc::aoc;
 use itertools::Itertools;
 use std::cmp::Ordering;
 
-aoc!(part_one, part_two);
+aoc!("Camel Cards", part_one, part_two);
 
 #[derive(Debug, Copy, Clone)]
 struct Hand {
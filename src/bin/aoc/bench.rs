@@ -0,0 +1,145 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The number of runs and warmup runs used to measure each step of a solution.
+#[derive(Debug, Copy, Clone)]
+pub struct Timing {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Timing {
+    /// A single run without warmup, used by `--time`.
+    pub const ONCE: Timing = Timing { warmup: 0, runs: 1 };
+
+    /// Returns the timing used by `--bench` for the specified number of [runs].
+    pub fn bench(runs: usize) -> Self {
+        Timing {
+            warmup: runs.min(3),
+            runs,
+        }
+    }
+
+    /// Returns the result of the last call to [f] along with the statistics of each measured call.
+    pub fn measure<T>(&self, mut f: impl FnMut() -> T) -> (T, Stats) {
+        for _ in 0..self.warmup {
+            black_box(f());
+        }
+
+        let mut samples = Vec::with_capacity(self.runs);
+        let mut result = None;
+
+        for _ in 0..self.runs.max(1) {
+            let start = Instant::now();
+            let value = black_box(f());
+            samples.push(start.elapsed());
+            result = Some(value);
+        }
+
+        (result.unwrap(), Stats::from_samples(samples))
+    }
+}
+
+/// Summary statistics of a set of measured durations.
+#[derive(Debug, Copy, Clone)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub runs: usize,
+}
+
+impl Stats {
+    /// Returns the statistics of the non-empty list of [samples].
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+
+        samples.sort();
+
+        let n = samples.len();
+
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / n as u32,
+            runs: n,
+        }
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.runs == 1 {
+            write!(f, "{:.2?}", self.median)
+        } else {
+            write!(
+                f,
+                "min {:.2?}, median {:.2?}, mean {:.2?}",
+                self.min, self.median, self.mean
+            )
+        }
+    }
+}
+
+/// The measured steps of a single solution, or None for each step that did not complete.
+pub struct Row {
+    pub name: String,
+    /// False if the solution has no parse step, in which case [parse] is None and counts as nothing in the total.
+    pub has_parse_step: bool,
+    pub parse: Option<Duration>,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+}
+
+impl Row {
+    fn total(&self) -> Option<Duration> {
+        let parse = if self.has_parse_step {
+            self.parse?
+        } else {
+            Duration::ZERO
+        };
+
+        Some(parse + self.part_one? + self.part_two?)
+    }
+}
+
+/// Prints a table of the median duration of each step of each solution.
+pub fn print_summary(rows: &[Row]) {
+    fn cell(duration: Option<Duration>) -> String {
+        duration.map_or(String::from("-"), |d| format!("{d:.2?}"))
+    }
+
+    println!();
+    println!(
+        "{:<8} {:>12} {:>12} {:>12} {:>12}",
+        "day", "parse", "part one", "part two", "total"
+    );
+
+    for row in rows {
+        println!(
+            "{:<8} {:>12} {:>12} {:>12} {:>12}",
+            row.name,
+            cell(row.parse),
+            cell(row.part_one),
+            cell(row.part_two),
+            cell(row.total()),
+        );
+    }
+
+    let total = rows.iter().flat_map(Row::total).sum::<Duration>();
+
+    println!(
+        "{:<8} {:>12} {:>12} {:>12} {:>12}",
+        "total",
+        "",
+        "",
+        "",
+        cell(Some(total))
+    );
+}
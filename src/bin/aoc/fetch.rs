@@ -0,0 +1,28 @@
+use std::process::ExitCode;
+
+use aoc::client::{Cache, Client, Config};
+use aoc::input;
+
+/// Downloads the input for the specified [year] and [day] into the input directory, unless it is already there.
+pub fn fetch(year: u32, day: u32) -> ExitCode {
+    match download(year, day) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn download(year: u32, day: u32) -> Result<(), String> {
+    let path = input::default_path(&format!("{year}_{day:02}"));
+
+    let client = Client::new(Config::load()?);
+
+    match client.cache_input(year, day, &path)? {
+        Cache::Downloaded => println!("downloaded {}", path.display()),
+        Cache::AlreadyCached => println!("{} is already cached", path.display()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc::{cli, Part};
+
+use crate::bench::Timing;
+use crate::run::{RunOptions, Selection};
+
+mod bench;
+mod fetch;
+mod new;
+mod run;
+mod scrape;
+mod submit;
+mod verify;
+
+aoc::registry!();
+
+const USAGE: &str = "\
+usage:
+    aoc run <year> [<day>] [options]    run every day of a year, or a single day
+    aoc run --all [options]             run every day
+    aoc verify [<year> [<day>]]         check every day, or a year or single day, against its answers
+    aoc new <year> <day> [--title <title>]
+                                        create the files for a new day from a template
+    aoc fetch <year> <day>              download the input for a day, unless it is already cached
+    aoc scrape <year> <day> [<page>]    write the examples of a day from its description, saved or downloaded
+    aoc submit <year> <day> <part>      solve a part and submit the answer, unless it is known to be wrong
+    aoc list                            list every registered day
+
+options:
+    --examples                          check each example before running the real input
+    --time                              measure parsing and each part once
+    --bench <runs>                      measure parsing and each part over repeated runs";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.split_first() {
+        Some((&"run", rest)) => {
+            parse_run_options(rest).map(|options| run::run(solutions(), options))
+        }
+        Some((&"verify", rest)) => {
+            parse_verify_selection(rest).map(|selection| verify::verify(solutions(), selection))
+        }
+        Some((&"new", rest)) => {
+            parse_new_args(rest).map(|(year, day, title)| new::new(year, day, &title))
+        }
+        Some((&"fetch", rest)) => parse_day(rest).map(|(year, day)| fetch::fetch(year, day)),
+        Some((&"scrape", rest)) => parse_scrape_args(rest)
+            .map(|(year, day, page)| scrape::scrape(year, day, page.as_deref())),
+        Some((&"submit", rest)) => parse_submit_args(rest)
+            .map(|(year, day, part)| submit::submit(solutions(), year, day, part)),
+        Some((&"list", [])) => Ok(list()),
+        _ => Err(String::from("expected a command")),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Returns the options described by the arguments of the `run` command.
+fn parse_run_options(args: &[&str]) -> Result<RunOptions, String> {
+    let mut options = RunOptions::default();
+    let mut numbers = vec![];
+    let mut all = false;
+
+    let mut args = args.iter();
+
+    while let Some(&arg) = args.next() {
+        match arg {
+            "--all" => all = true,
+            "--time" => options.timing = Some(Timing::ONCE),
+            "--examples" => options.examples = true,
+            "--bench" => {
+                let runs = args
+                    .next()
+                    .ok_or("expected a number of runs after --bench")?;
+                options.timing = Some(Timing::bench(parse_number(runs)? as usize));
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown option `{arg}`")),
+            _ => numbers.push(parse_number(arg)?),
+        }
+    }
+
+    options.selection = match (all, numbers.as_slice()) {
+        (true, []) => Selection::default(),
+        (false, []) => return Err(String::from("expected a year, or --all")),
+        (false, numbers) => parse_selection(numbers)?,
+        _ => return Err(String::from("too many arguments")),
+    };
+
+    Ok(options)
+}
+
+/// Returns the selection described by the arguments of the `verify` command, which defaults to every day.
+fn parse_verify_selection(args: &[&str]) -> Result<Selection, String> {
+    let numbers = args
+        .iter()
+        .map(|s| parse_number(s))
+        .collect::<Result<Vec<_>, _>>()?;
+    parse_selection(&numbers)
+}
+
+/// Returns the selection of an optional year and day.
+fn parse_selection(numbers: &[u32]) -> Result<Selection, String> {
+    match numbers {
+        [] => Ok(Selection::default()),
+        [year] => Ok(Selection {
+            year: Some(*year),
+            day: None,
+        }),
+        [year, day] => Ok(Selection {
+            year: Some(*year),
+            day: Some(*day),
+        }),
+        _ => Err(String::from("too many arguments")),
+    }
+}
+
+/// Returns the year and day of a command that acts on a single day.
+fn parse_day(args: &[&str]) -> Result<(u32, u32), String> {
+    match args {
+        [year, day] => Ok((parse_number(year)?, parse_number(day)?)),
+        _ => Err(String::from("expected a year and day")),
+    }
+}
+
+/// Returns the year, day and optional page path described by the arguments of the `scrape` command.
+fn parse_scrape_args(args: &[&str]) -> Result<(u32, u32, Option<PathBuf>), String> {
+    match args {
+        [year, day] => Ok((parse_number(year)?, parse_number(day)?, None)),
+        [year, day, page] => Ok((
+            parse_number(year)?,
+            parse_number(day)?,
+            Some(PathBuf::from(page)),
+        )),
+        _ => Err(String::from(
+            "expected a year and day, and optionally a page",
+        )),
+    }
+}
+
+/// Returns the year, day and part described by the arguments of the `submit` command.
+fn parse_submit_args(args: &[&str]) -> Result<(u32, u32, Part), String> {
+    match args {
+        [year, day, part] => Ok((
+            parse_number(year)?,
+            parse_number(day)?,
+            cli::parse_part(part)?,
+        )),
+        _ => Err(String::from("expected a year, day and part")),
+    }
+}
+
+/// Returns the year, day and title described by the arguments of the `new` command.
+fn parse_new_args(args: &[&str]) -> Result<(u32, u32, String), String> {
+    match args {
+        [year, day] => Ok((parse_number(year)?, parse_number(day)?, String::new())),
+        [year, day, "--title", title] => {
+            Ok((parse_number(year)?, parse_number(day)?, title.to_string()))
+        }
+        _ => Err(String::from(
+            "expected a year and day, and optionally --title",
+        )),
+    }
+}
+
+fn parse_number(s: &str) -> Result<u32, String> {
+    s.parse().map_err(|_| format!("invalid number `{s}`"))
+}
+
+fn list() -> ExitCode {
+    for solution in solutions() {
+        println!("{} {}", solution.name(), solution.title());
+    }
+
+    ExitCode::SUCCESS
+}
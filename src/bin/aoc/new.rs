@@ -0,0 +1,78 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc::{answers, examples, input};
+
+/// The directory containing the solution files.
+const BIN_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/bin");
+
+/// The template of a new solution file, where `TITLE` is replaced with the puzzle title.
+const SOLUTION_TEMPLATE: &str = include_str!("template.txt");
+
+const ANSWERS_TEMPLATE: &str = "\
+# part_one = 0
+# part_two = 0
+";
+
+const EXAMPLES_TEMPLATE: &str = "\
+[[example]]
+name = \"example\"
+# part_one = 0
+# part_two = 0
+input = '''
+'''
+";
+
+/// Creates the solution, input, example and answer files for a new day, without overwriting any existing file.
+pub fn new(year: u32, day: u32, title: &str) -> ExitCode {
+    match create(year, day, title) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn create(year: u32, day: u32, title: &str) -> Result<(), String> {
+    if !(2015..=9999).contains(&year) || !(1..=25).contains(&day) {
+        return Err(format!("invalid puzzle {year} day {day}"));
+    }
+
+    let name = format!("{year}_{day:02}");
+
+    let files = [
+        (
+            PathBuf::from(BIN_DIR).join(format!("{name}.rs")),
+            SOLUTION_TEMPLATE.replace("TITLE", &format!("{title:?}")),
+        ),
+        (input::default_path(&name), String::new()),
+        (examples::path(&name), String::from(EXAMPLES_TEMPLATE)),
+        (answers::path(&name), String::from(ANSWERS_TEMPLATE)),
+    ];
+
+    let existing: Vec<String> = files
+        .iter()
+        .filter(|(path, _)| path.exists())
+        .map(|(path, _)| path.display().to_string())
+        .collect();
+
+    if !existing.is_empty() {
+        return Err(format!("refusing to overwrite {}", existing.join(", ")));
+    }
+
+    for (path, contents) in &files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("failed to create {}: {e}", parent.display()))?;
+        }
+
+        fs::write(path, contents)
+            .map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+
+        println!("created {}", path.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,264 @@
+use std::fmt::{self, Display};
+use std::panic::{self, AssertUnwindSafe};
+use std::process::ExitCode;
+
+use aoc::answers::{Answers, Check};
+use aoc::examples;
+use aoc::input::InputSource;
+use aoc::{Answer, Error, Part, Solution};
+
+use crate::bench::{self, Row, Stats, Timing};
+
+/// The set of solutions selected on the command line.
+#[derive(Default)]
+pub struct Selection {
+    pub year: Option<u32>,
+    pub day: Option<u32>,
+}
+
+impl Selection {
+    pub fn contains(&self, solution: &dyn Solution) -> bool {
+        self.year.is_none_or(|y| y == solution.year())
+            && self.day.is_none_or(|d| d == solution.day())
+    }
+}
+
+/// The options of the `run` command.
+#[derive(Default)]
+pub struct RunOptions {
+    pub selection: Selection,
+    pub timing: Option<Timing>,
+    pub examples: bool,
+}
+
+/// Runs each selected solution against its input, continuing past any that fail.
+pub fn run(solutions: Vec<Box<dyn Solution>>, options: RunOptions) -> ExitCode {
+    let selected: Vec<_> = solutions
+        .into_iter()
+        .filter(|s| options.selection.contains(s.as_ref()))
+        .collect();
+
+    if selected.is_empty() {
+        eprintln!("error: no matching solutions");
+        return ExitCode::FAILURE;
+    }
+
+    let timing = options.timing.unwrap_or(Timing::ONCE);
+
+    let mut failed = false;
+    let mut rows = vec![];
+
+    for solution in selected {
+        println!("{} {}", solution.name(), solution.title());
+
+        let mut row = Row {
+            name: solution.name(),
+            has_parse_step: solution.has_parse_step(),
+            parse: None,
+            part_one: None,
+            part_two: None,
+        };
+
+        if options.examples && !run_examples(solution.as_ref()) {
+            failed = true;
+            rows.push(row);
+            continue;
+        }
+
+        let text = match InputSource::Default.read_checked(&solution.name()) {
+            Ok((text, diagnostics)) => {
+                for diagnostic in diagnostics {
+                    println!("  {}: {diagnostic}", diagnostic.label());
+                }
+
+                text
+            }
+            Err(message) => {
+                println!("  {message}");
+                failed = true;
+                rows.push(row);
+                continue;
+            }
+        };
+
+        let answers = match Answers::load(&solution.name()) {
+            Ok(answers) => answers,
+            Err(message) => {
+                println!("  {message}");
+                failed = true;
+                Answers::default()
+            }
+        };
+
+        // Without a parse step each part parses the text itself, so there is nothing worth measuring here.
+
+        let parse = || {
+            if solution.has_parse_step() {
+                let (input, stats) = measure(timing, || solution.parse(&text))?;
+                Ok((input, Some(stats)))
+            } else {
+                Ok((solution.parse(&text)?, None))
+            }
+        };
+
+        let input = match attempt(parse) {
+            Ok((input, stats)) => {
+                if options.timing.is_some() {
+                    match stats {
+                        Some(stats) => println!("  parse: {stats}"),
+                        None => println!("  parse: -"),
+                    }
+                }
+
+                row.parse = stats.map(|stats| stats.median);
+                input
+            }
+            Err(failure) => {
+                println!("  parse: {failure}");
+                failed = true;
+                rows.push(row);
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            match attempt(|| measure(timing, || solution.solve(&input, part))) {
+                Ok((answer, stats)) => {
+                    let check = Check::new(answers.get(part), &answer);
+                    let status = status(&check);
+
+                    if options.timing.is_some() {
+                        println!("  {part}: {}{status} ({stats})", display(&answer));
+                    } else {
+                        println!("  {part}: {}{status}", display(&answer));
+                    }
+
+                    failed |= matches!(check, Check::Fail { .. });
+
+                    match part {
+                        Part::One => row.part_one = Some(stats.median),
+                        Part::Two => row.part_two = Some(stats.median),
+                    }
+                }
+                Err(failure) => {
+                    println!("  {part}: {failure}");
+                    failed = true;
+                }
+            }
+        }
+
+        rows.push(row);
+    }
+
+    if options.timing.is_some() {
+        bench::print_summary(&rows);
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Runs the [solution] against each of its examples, returning true if every expected answer matched.
+fn run_examples(solution: &dyn Solution) -> bool {
+    let examples = match examples::load(&solution.name()) {
+        Ok(examples) => examples,
+        Err(message) => {
+            println!("  {message}");
+            return false;
+        }
+    };
+
+    if examples.is_empty() {
+        println!("  no examples");
+    }
+
+    let mut passed = true;
+
+    for example in examples {
+        let name = &example.name;
+
+        let input = match attempt(|| solution.parse(&example.input)) {
+            Ok(input) => input,
+            Err(failure) => {
+                println!("  example {name} parse: {failure}");
+                passed = false;
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let Some(expected) = example.answers.get(part) else {
+                continue;
+            };
+
+            match attempt(|| solution.solve(&input, part)) {
+                Ok(answer) => {
+                    let check = Check::new(Some(expected), &answer);
+                    println!(
+                        "  example {name} {part}: {}{}",
+                        display(&answer),
+                        status(&check)
+                    );
+                    passed &= check == Check::Pass;
+                }
+                Err(failure) => {
+                    println!("  example {name} {part}: {failure}");
+                    passed = false;
+                }
+            }
+        }
+    }
+
+    passed
+}
+
+/// Returns the suffix describing the result of checking an answer.
+fn status(check: &Check) -> String {
+    match check {
+        Check::Pass => String::from(" [correct]"),
+        Check::Fail { expected } => format!(" [incorrect, expected {}]", display(expected)),
+        Check::Missing => String::new(),
+    }
+}
+
+/// Returns the answer as shown after a label, starting multi-line answers on an indented new line.
+pub fn display(answer: &Answer) -> String {
+    if answer.is_multiline() {
+        format!("\n    {}", answer.normalized().replace('\n', "\n    "))
+    } else {
+        answer.normalized()
+    }
+}
+
+/// The reason a step of a solution did not produce a value.
+pub enum Failure {
+    Error(Error),
+    Panic,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(e) => write!(f, "error: {}", e.report().replace('\n', "\n    ")),
+            Failure::Panic => write!(f, "panicked"),
+        }
+    }
+}
+
+/// Returns the result of a step of a solution, catching any panic.
+pub fn attempt<T>(f: impl FnOnce() -> aoc::Result<T>) -> Result<T, Failure> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err(Failure::Error(e)),
+        Err(_) => Err(Failure::Panic),
+    }
+}
+
+/// Returns the result of measuring the step [f] with the specified [timing], stopping at the first error.
+fn measure<T>(timing: Timing, mut f: impl FnMut() -> aoc::Result<T>) -> aoc::Result<(T, Stats)> {
+    let (result, stats) = timing.measure(&mut f);
+    Ok((result?, stats))
+}
@@ -0,0 +1,74 @@
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use aoc::client::{Client, Config};
+use aoc::examples::{self, Example};
+use aoc::scrape::scrape_examples;
+
+/// Writes the examples found in the description of the specified day, read from the saved [page] or else downloaded,
+/// unless the day already has examples.
+pub fn scrape(year: u32, day: u32, page: Option<&Path>) -> ExitCode {
+    match write_examples(year, day, page) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn write_examples(year: u32, day: u32, page: Option<&Path>) -> Result<(), String> {
+    let name = format!("{year}_{day:02}");
+    let path = examples::path(&name);
+
+    // The stub written by `aoc new` has an empty input, and does not count as existing examples.
+
+    if examples::load(&name)?.iter().any(|e| !e.input.is_empty()) {
+        return Err(format!("{} already has examples", path.display()));
+    }
+
+    let html = match page {
+        Some(page) => fs::read_to_string(page)
+            .map_err(|e| format!("failed to read {}: {e}", page.display()))?,
+        None => Client::new(Config::load()?).get(&format!("/{year}/day/{day}"))?,
+    };
+
+    let examples = scrape_examples(&html);
+
+    if examples.is_empty() {
+        return Err(String::from("no examples found in the puzzle description"));
+    }
+
+    fs::write(&path, examples::to_toml(&examples)?)
+        .map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+
+    for example in &examples {
+        println!("example {}: {}", example.name, summary(example));
+    }
+
+    println!(
+        "wrote {}, check the examples against the description",
+        path.display()
+    );
+
+    Ok(())
+}
+
+fn summary(example: &Example) -> String {
+    let lines = example.input.lines().count();
+
+    let answers = [
+        ("part one", &example.answers.part_one),
+        ("part two", &example.answers.part_two),
+    ]
+    .into_iter()
+    .filter_map(|(part, answer)| answer.as_ref().map(|a| format!("{part} {a}")))
+    .collect::<Vec<_>>();
+
+    if answers.is_empty() {
+        format!("{lines} lines, no answers")
+    } else {
+        format!("{lines} lines, {}", answers.join(", "))
+    }
+}
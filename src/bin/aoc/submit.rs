@@ -0,0 +1,87 @@
+use std::process::ExitCode;
+
+use aoc::answers::{self, Answers};
+use aoc::client::{Client, Config};
+use aoc::history::{self, Attempt, History, Outcome};
+use aoc::input::InputSource;
+use aoc::{Answer, Part, Solution};
+
+use crate::run::{attempt, display};
+
+/// Solves the [part] of the specified day and submits the answer, unless the submission history shows that it would
+/// be rejected.
+pub fn submit(solutions: Vec<Box<dyn Solution>>, year: u32, day: u32, part: Part) -> ExitCode {
+    let Some(solution) = solutions
+        .iter()
+        .find(|s| s.year() == year && s.day() == day)
+    else {
+        eprintln!("error: no solution for {year}_{day:02}");
+        return ExitCode::FAILURE;
+    };
+
+    match solve_and_submit(solution.as_ref(), part) {
+        Ok(Outcome::Correct) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn solve_and_submit(solution: &dyn Solution, part: Part) -> Result<Outcome, String> {
+    let name = solution.name();
+
+    let answer = solve(solution, part)?;
+
+    println!("{name} {part}: {}", display(&answer));
+
+    if answer.is_multiline() {
+        return Err(String::from(
+            "a grid answer must be read and submitted by hand",
+        ));
+    }
+
+    let mut history = History::load(&name)?;
+
+    if let Some(reason) = history.refusal(part, &answer, history::now()) {
+        return Err(format!("not submitted, {reason}"));
+    }
+
+    let client = Client::new(Config::load()?);
+
+    let response =
+        client.submit_answer(solution.year(), solution.day(), part, &answer.normalized())?;
+    let outcome = Outcome::parse(&response).ok_or("unrecognized response to the submission")?;
+
+    history.record(Attempt {
+        part,
+        answer: answer.clone(),
+        outcome,
+        retry_after: Outcome::parse_retry_after(&response),
+        time: history::now(),
+    });
+
+    history.save(&name)?;
+
+    println!("{outcome}");
+
+    if outcome == Outcome::Correct && Answers::load(&name)?.get(part).is_none() {
+        println!("record the answer in {}", answers::path(&name).display());
+    }
+
+    Ok(outcome)
+}
+
+/// Returns the answer to the [part] of the [solution] for its default input.
+fn solve(solution: &dyn Solution, part: Part) -> Result<Answer, String> {
+    let (text, diagnostics) = InputSource::Default.read_checked(&solution.name())?;
+
+    for diagnostic in diagnostics {
+        println!("{}: {diagnostic}", diagnostic.label());
+    }
+
+    let input = attempt(|| solution.parse(&text)).map_err(|f| format!("parse {f}"))?;
+
+    attempt(|| solution.solve(&input, part)).map_err(|f| format!("{part} {f}"))
+}
@@ -0,0 +1,90 @@
+use std::process::ExitCode;
+
+use aoc::answers::{Answers, Check};
+use aoc::input::InputSource;
+use aoc::{Part, Solution};
+
+use crate::run::{attempt, display, Selection};
+
+/// Checks each selected solution against its expected answers, reporting every pass, failure and missing answer.
+pub fn verify(solutions: Vec<Box<dyn Solution>>, selection: Selection) -> ExitCode {
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut missing = 0;
+
+    for solution in solutions.iter().filter(|s| selection.contains(s.as_ref())) {
+        let name = solution.name();
+
+        let answers = match Answers::load(&name) {
+            Ok(answers) => answers,
+            Err(message) => {
+                println!("{name}            FAIL     {message}");
+                failed += 2;
+                continue;
+            }
+        };
+
+        let text = match InputSource::Default.read_checked(&name) {
+            Ok((text, diagnostics)) => {
+                for diagnostic in diagnostics {
+                    println!("{name}            {:<8} {diagnostic}", diagnostic.label());
+                }
+
+                text
+            }
+            Err(message) => {
+                println!("{name}            FAIL     {message}");
+                failed += 2;
+                continue;
+            }
+        };
+
+        let input = match attempt(|| solution.parse(&text)) {
+            Ok(input) => input,
+            Err(failure) => {
+                println!("{name}            FAIL     parse {failure}");
+                failed += 2;
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let answer = match attempt(|| solution.solve(&input, part)) {
+                Ok(answer) => answer,
+                Err(failure) => {
+                    println!("{name}  {part}  FAIL     {failure}");
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            match Check::new(answers.get(part), &answer) {
+                Check::Pass => {
+                    println!("{name}  {part}  pass");
+                    passed += 1;
+                }
+                Check::Fail { expected } => {
+                    println!(
+                        "{name}  {part}  FAIL     expected {}, got {}",
+                        display(&expected),
+                        display(&answer)
+                    );
+                    failed += 1;
+                }
+                Check::Missing => {
+                    println!("{name}  {part}  missing  got {}", display(&answer));
+                    missing += 1;
+                }
+            }
+        }
+    }
+
+    println!();
+    println!("{passed} passed, {failed} failed, {missing} missing");
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,96 @@
+use std::process::ExitCode;
+
+use crate::input::InputSource;
+use crate::{Part, Solution};
+
+/// Runs the [solution] of a single day binary, as configured by the command-line arguments.
+pub fn main(solution: &dyn Solution) -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("error: {message}\n");
+            eprintln!(
+                "usage: {} [--input <path> | -] [--part 1|2]",
+                solution.name()
+            );
+            return ExitCode::from(2);
+        }
+    };
+
+    let text = match options.source.read_checked(&solution.name()) {
+        Ok((text, diagnostics)) => {
+            for diagnostic in diagnostics {
+                eprintln!("{}: {diagnostic}", diagnostic.label());
+            }
+
+            text
+        }
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let input = match solution.parse(&text) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", e.report());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for part in options.parts {
+        match solution.solve(&input, part) {
+            Ok(answer) => println!("{answer}"),
+            Err(e) => {
+                eprintln!("error: {part}: {}", e.report());
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// The options accepted by a day binary.
+struct Options {
+    source: InputSource,
+    parts: Vec<Part>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        source: InputSource::Default,
+        parts: Part::ALL.to_vec(),
+    };
+
+    let mut args = args.iter().map(String::as_str);
+
+    while let Some(arg) = args.next() {
+        match arg {
+            "--input" => {
+                let path = args.next().ok_or("expected a path after --input")?;
+                options.source = InputSource::from_arg(path);
+            }
+            "-" => options.source = InputSource::Stdin,
+            "--part" => {
+                let part = args.next().ok_or("expected 1 or 2 after --part")?;
+                options.parts = vec![parse_part(part)?];
+            }
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    Ok(options)
+}
+
+/// Returns the part described by a command-line [arg].
+pub fn parse_part(arg: &str) -> Result<Part, String> {
+    match arg {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("invalid part `{arg}`, expected 1 or 2")),
+    }
+}
@@ -0,0 +1,321 @@
+use std::path::Path;
+use std::time::Duration;
+
+use toml::Table;
+
+use crate::Part;
+
+/// The site that puzzles are downloaded from, unless configured otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The optional config file in the repository root, which should not be committed as it holds the session cookie.
+pub const CONFIG_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/aoc.toml");
+
+const USER_AGENT: &str = "github.com/seep/advent";
+
+/// The settings used to talk to the puzzle site.
+///
+/// Each setting is read from an environment variable, falling back to the config file:
+///
+/// | setting    | variable       | key in `aoc.toml` |
+/// |------------|----------------|-------------------|
+/// | session    | `AOC_SESSION`  | `session`         |
+/// | base URL   | `AOC_BASE_URL` | `base_url`        |
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Config {
+    /// Returns the config from the environment and [CONFIG_PATH].
+    pub fn load() -> Result<Config, String> {
+        let table = read_config_file(Path::new(CONFIG_PATH))?;
+
+        Ok(Config::resolve(|var| std::env::var(var).ok(), &table))
+    }
+
+    /// Returns the config from the variables given by [env], falling back to the config file [table].
+    fn resolve(env: impl Fn(&str) -> Option<String>, table: &Table) -> Config {
+        let setting = |var: &str, key: &str| {
+            env(var)
+                .or_else(|| table.get(key).and_then(|v| v.as_str()).map(String::from))
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        };
+
+        Config {
+            session: setting("AOC_SESSION", "session"),
+            base_url: setting("AOC_BASE_URL", "base_url")
+                .unwrap_or_else(|| String::from(DEFAULT_BASE_URL)),
+        }
+    }
+}
+
+/// Whether [Client::cache_input] downloaded an input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cache {
+    Downloaded,
+    AlreadyCached,
+}
+
+/// A client for the puzzle site.
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// Creates a new Client with the given [config].
+    pub fn new(config: Config) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(USER_AGENT)
+            .build();
+
+        Client { config, agent }
+    }
+
+    /// Returns the text of the puzzle input for the specified [year] and [day].
+    pub fn fetch_input(&self, year: u32, day: u32) -> Result<String, String> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    /// Downloads the puzzle input for the specified [year] and [day] to the [path], unless a non-empty file is
+    /// already there. An empty file is the placeholder created by `aoc new`, so it does not count as cached.
+    ///
+    /// The input is stored without its final newline, like the other input files.
+    pub fn cache_input(&self, year: u32, day: u32, path: &Path) -> Result<Cache, String> {
+        if std::fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+            return Ok(Cache::AlreadyCached);
+        }
+
+        let text = self.fetch_input(year, day)?;
+        let text = text.strip_suffix('\n').unwrap_or(&text);
+
+        std::fs::write(path, text)
+            .map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+
+        Ok(Cache::Downloaded)
+    }
+
+    /// Submits the [answer] to the [part] of the puzzle for the specified [year] and [day], returning the HTML
+    /// response.
+    pub fn submit_answer(
+        &self,
+        year: u32,
+        day: u32,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, String> {
+        let level = match part {
+            Part::One => "1",
+            Part::Two => "2",
+        };
+
+        self.post_form(
+            &format!("/{year}/day/{day}/answer"),
+            &[("level", level), ("answer", answer)],
+        )
+    }
+
+    /// Returns the body of a GET request to the [path] on the puzzle site.
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        let request = self.agent.get(&self.url(path));
+        let request = self.authorize(request)?;
+
+        read_response(&self.url(path), request.call())
+    }
+
+    /// Returns the body of a POST request of the form [fields] to the [path] on the puzzle site.
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> Result<String, String> {
+        let request = self.agent.post(&self.url(path));
+        let request = self.authorize(request)?;
+
+        read_response(&self.url(path), request.send_form(fields))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.config.base_url.trim_end_matches('/'))
+    }
+
+    fn authorize(&self, request: ureq::Request) -> Result<ureq::Request, String> {
+        let session = self.config.session.as_ref().ok_or_else(|| {
+            format!("no session cookie, set AOC_SESSION or `session` in {CONFIG_PATH}")
+        })?;
+
+        Ok(request.set("Cookie", &format!("session={session}")))
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| format!("failed to read response from {url}: {e}")),
+        Err(ureq::Error::Status(code, response)) => Err(format!(
+            "request to {url} failed with status {code} {}",
+            response.status_text()
+        )),
+        Err(e) => Err(format!("request to {url} failed: {e}")),
+    }
+}
+
+fn read_config_file(path: &Path) -> Result<Table, String> {
+    if !path.exists() {
+        return Ok(Table::new());
+    }
+
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+
+    text.parse().map_err(|e: toml::de::Error| {
+        format!("invalid config in {}: {}", path.display(), e.message())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    /// A stand-in for the puzzle site that answers every request with [body], returning its base URL and the
+    /// requests it has received.
+    fn serve(body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let received = Arc::clone(&requests);
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&mut stream);
+                let mut request = String::new();
+                let mut length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+
+                    request.push_str(&line);
+
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+
+                received.lock().unwrap().push(request);
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn client(base_url: &str) -> Client {
+        Client::new(Config {
+            session: Some(String::from("cookie")),
+            base_url: base_url.to_string(),
+        })
+    }
+
+    #[test]
+    fn prefers_the_environment_to_the_config_file() {
+        let table: Table = "session = \"file\"\nbase_url = \"http://file\""
+            .parse()
+            .unwrap();
+
+        let config = Config::resolve(
+            |var| (var == "AOC_BASE_URL").then(|| String::from("http://env/")),
+            &table,
+        );
+
+        assert_eq!(config.base_url, "http://env/");
+        assert_eq!(config.session.as_deref(), Some("file"));
+
+        let config = Config::resolve(|_| None, &Table::new());
+
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert_eq!(config.session, None);
+    }
+
+    #[test]
+    fn fetches_inputs_from_the_base_url() {
+        let (url, requests) = serve("1\n2\n");
+
+        let text = client(&format!("{url}/")).fetch_input(2023, 1).unwrap();
+
+        assert_eq!(text, "1\n2\n");
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /2023/day/1/input "));
+        assert!(requests[0].contains("session=cookie"));
+    }
+
+    #[test]
+    fn caches_inputs_without_the_final_newline() {
+        let (url, requests) = serve("1\n2\n");
+        let path = std::env::temp_dir().join(format!("aoc_cache_{}.txt", std::process::id()));
+
+        std::fs::write(&path, "").unwrap();
+
+        let client = client(&url);
+
+        assert_eq!(client.cache_input(2023, 1, &path), Ok(Cache::Downloaded));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1\n2");
+
+        assert_eq!(client.cache_input(2023, 1, &path), Ok(Cache::AlreadyCached));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn submits_answers_as_a_form() {
+        let (url, requests) = serve("<article><p>That's the right answer!</p></article>");
+
+        let response = client(&url)
+            .submit_answer(2023, 1, Part::Two, "42")
+            .unwrap();
+
+        assert!(response.contains("right answer"));
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("POST /2023/day/1/answer "));
+        assert!(requests[0].ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn requires_a_session() {
+        let client = Client::new(Config {
+            session: None,
+            base_url: String::from("http://127.0.0.1:9"),
+        });
+
+        assert!(client
+            .fetch_input(2023, 1)
+            .unwrap_err()
+            .contains("AOC_SESSION"));
+    }
+}
@@ -0,0 +1,494 @@
+use std::any::type_name;
+
+use crate::error::{Error, Result};
+use crate::parse::Integer;
+
+/// The result of a parser, with the value and the input after it.
+pub type ParseResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+/// The reason a parser did not match, with the input where it stopped and what it expected to find there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure<'a> {
+    pub rest: &'a str,
+    pub expected: Vec<String>,
+}
+
+impl<'a> Failure<'a> {
+    /// Creates a new Failure at the [rest] of the input, which did not start with the [expected] text.
+    pub fn new(rest: &'a str, expected: impl Into<String>) -> Self {
+        Failure {
+            rest,
+            expected: vec![expected.into()],
+        }
+    }
+
+    /// Returns the failure that got further into the input, or both expectations if they stopped at the same place.
+    fn merge(self, other: Failure<'a>) -> Failure<'a> {
+        match self.rest.len().cmp(&other.rest.len()) {
+            std::cmp::Ordering::Less => self,
+            std::cmp::Ordering::Greater => other,
+            std::cmp::Ordering::Equal => {
+                let mut expected = self.expected;
+
+                for e in other.expected {
+                    if !expected.contains(&e) {
+                        expected.push(e);
+                    }
+                }
+
+                Failure { expected, ..self }
+            }
+        }
+    }
+
+    /// Returns the failure as an error attached to the character where it stopped.
+    pub fn into_error(self) -> Error {
+        let expected = self.expected.join(" or ");
+
+        let found = match self.rest.chars().next() {
+            Some(c) => format!("`{c}`"),
+            None => String::from("the end of the input"),
+        };
+
+        let width = self.rest.chars().next().map_or(0, char::len_utf8);
+
+        Error::new(format!("expected {expected}, found {found}")).at(&self.rest[..width])
+    }
+}
+
+/// Parses a value from the start of the input, returning it with the input after it.
+///
+/// Parsers are built from the functions in this module, or from a plain function with [from_fn], and run with
+/// [parse_all], which turns the furthest failure into an [Error] attached to the input:
+///
+/// ```ignore
+/// let cube = pair(terminated(number::<u32>(), tag(" ")), word());
+/// let draw = sep_by1(cube, tag(", "));
+/// let draws = parse_all(sep_by1(draw, tag("; ")), "3 blue, 4 red; 1 red")?;
+/// ```
+///
+/// Every parser backtracks, except that a repetition stops with an error if an item fails after consuming input, so
+/// that the error points at the item rather than at whatever follows the repetition.
+///
+/// A reference to a parser is a parser too, so a parser used for each line of an input only needs to be built once.
+pub trait Parser<'a, T> {
+    fn parse(&self, input: &'a str) -> ParseResult<'a, T>;
+}
+
+/// A parser that shares another by reference, so that it can be built once and used many times.
+impl<'a, T, P: Parser<'a, T> + ?Sized> Parser<'a, T> for &P {
+    fn parse(&self, input: &'a str) -> ParseResult<'a, T> {
+        (**self).parse(input)
+    }
+}
+
+/// Returns a parser that calls [f], for parsers that are not built from the other functions of this module.
+pub fn from_fn<'a, T>(f: impl Fn(&'a str) -> ParseResult<'a, T>) -> impl Parser<'a, T> {
+    FromFn(f)
+}
+
+struct FromFn<F>(F);
+
+impl<'a, T, F> Parser<'a, T> for FromFn<F>
+where
+    F: Fn(&'a str) -> ParseResult<'a, T>,
+{
+    fn parse(&self, input: &'a str) -> ParseResult<'a, T> {
+        (self.0)(input)
+    }
+}
+
+/// Returns the value of the [parser] for the whole of [s], or the error of the furthest failure.
+pub fn parse_all<'a, T>(parser: impl Parser<'a, T>, s: &'a str) -> Result<T> {
+    match parser.parse(s) {
+        Ok((value, "")) => Ok(value),
+        Ok((_, rest)) => Err(Failure::new(rest, "the end of the input").into_error()),
+        Err(failure) => Err(failure.into_error()),
+    }
+}
+
+/// Returns a parser that matches the [literal] text.
+pub fn tag<'a>(literal: &'static str) -> impl Parser<'a, &'a str> {
+    from_fn(move |input| match input.strip_prefix(literal) {
+        Some(rest) => Ok((&input[..literal.len()], rest)),
+        None => Err(Failure::new(input, format!("`{literal}`"))),
+    })
+}
+
+/// Returns a parser that matches the longest run of characters accepted by the [predicate], which may be empty.
+pub fn take_while<'a>(predicate: impl Fn(char) -> bool) -> impl Parser<'a, &'a str> {
+    from_fn(move |input| {
+        let end = input.find(|c| !predicate(c)).unwrap_or(input.len());
+        Ok((&input[..end], &input[end..]))
+    })
+}
+
+/// Returns a parser that matches the longest run of characters accepted by the [predicate], which must not be
+/// empty, described as [expected] if it is.
+pub fn take_while1<'a>(
+    expected: &'static str,
+    predicate: impl Fn(char) -> bool,
+) -> impl Parser<'a, &'a str> {
+    let run = take_while(predicate);
+
+    from_fn(move |input| match run.parse(input)? {
+        ("", _) => Err(Failure::new(input, expected)),
+        result => Ok(result),
+    })
+}
+
+/// Returns a parser that matches a run of ASCII letters.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    take_while1("a word", |c| c.is_ascii_alphabetic())
+}
+
+/// Returns a parser that matches any number of spaces and tabs, including none.
+pub fn spaces<'a>() -> impl Parser<'a, &'a str> {
+    take_while(|c| c == ' ' || c == '\t')
+}
+
+/// Returns a parser that matches an integer of type [T], with a leading `-` if [T] is signed.
+pub fn number<'a, T: Integer>() -> impl Parser<'a, T> {
+    from_fn(move |input| {
+        let sign = usize::from(T::SIGNED && input.starts_with('-'));
+        let digits = input[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len() - sign);
+
+        if digits == 0 {
+            return Err(Failure::new(input, "a number"));
+        }
+
+        let (token, rest) = input.split_at(sign + digits);
+
+        match token.parse() {
+            Ok(value) => Ok((value, rest)),
+            Err(_) => Err(Failure::new(
+                input,
+                format!("a number that fits in {}", type_name::<T>()),
+            )),
+        }
+    })
+}
+
+/// Returns a parser that applies [f] to the value of the [parser].
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    from_fn(move |input| {
+        let (value, rest) = parser.parse(input)?;
+        Ok((f(value), rest))
+    })
+}
+
+/// Returns a parser that matches the [parser] if it can, and nothing otherwise.
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    from_fn(move |input| match parser.parse(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, input)),
+    })
+}
+
+/// Returns a parser that matches [a] then [b], returning both values.
+pub fn pair<'a, A, B>(a: impl Parser<'a, A>, b: impl Parser<'a, B>) -> impl Parser<'a, (A, B)> {
+    from_fn(move |input| {
+        let (a, rest) = a.parse(input)?;
+        let (b, rest) = b.parse(rest)?;
+        Ok(((a, b), rest))
+    })
+}
+
+/// Returns a parser that matches [prefix] then [parser], returning the value of [parser].
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    map(pair(prefix, parser), |(_, value)| value)
+}
+
+/// Returns a parser that matches [parser] then [suffix], returning the value of [parser].
+pub fn terminated<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    map(pair(parser, suffix), |(value, _)| value)
+}
+
+/// Returns a parser that matches [open], [parser] and [close], returning the value of [parser].
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, T> {
+    preceded(open, terminated(parser, close))
+}
+
+/// A tuple of parsers of the same type, tried in order by [alt].
+pub trait Alt<'a, T> {
+    fn parse_first(&self, input: &'a str) -> ParseResult<'a, T>;
+}
+
+macro_rules! impl_alt {
+    ($first:ident, $($rest:ident),*) => {
+        #[allow(non_snake_case)]
+        impl<'a, T, $first: Parser<'a, T>, $($rest: Parser<'a, T>),*> Alt<'a, T> for ($first, $($rest),*) {
+            fn parse_first(&self, input: &'a str) -> ParseResult<'a, T> {
+                let ($first, $($rest),*) = self;
+
+                let failure = match $first.parse(input) {
+                    Ok(result) => return Ok(result),
+                    Err(failure) => failure,
+                };
+
+                $(
+                    let failure = match $rest.parse(input) {
+                        Ok(result) => return Ok(result),
+                        Err(other) => failure.merge(other),
+                    };
+                )*
+
+                Err(failure)
+            }
+        }
+    };
+}
+
+impl_alt!(A, B);
+impl_alt!(A, B, C);
+impl_alt!(A, B, C, D);
+impl_alt!(A, B, C, D, E);
+impl_alt!(A, B, C, D, E, F);
+
+/// Returns a parser that matches the first of the [parsers] that matches, given as a tuple.
+pub fn alt<'a, T>(parsers: impl Alt<'a, T>) -> impl Parser<'a, T> {
+    from_fn(move |input| parsers.parse_first(input))
+}
+
+/// Returns a parser that matches the [parser] any number of times, including none.
+pub fn many<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    from_fn(move |input| repeat(&parser, input, 0))
+}
+
+/// Returns a parser that matches the [parser] at least once.
+pub fn many1<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    from_fn(move |input| repeat(&parser, input, 1))
+}
+
+/// Returns the values of matching the [parser] as many times as possible, which must be at least [min] times.
+fn repeat<'a, T>(
+    parser: &impl Parser<'a, T>,
+    mut input: &'a str,
+    min: usize,
+) -> ParseResult<'a, Vec<T>> {
+    let mut values = vec![];
+
+    loop {
+        match parser.parse(input) {
+            // A parser that matches nothing would match forever, so it ends the repetition, which then needs the
+            // matches it has so far to be enough.
+            Ok((_, rest)) if rest.len() == input.len() => {
+                if values.len() < min {
+                    return Err(Failure::new(input, "something to repeat"));
+                }
+
+                return Ok((values, input));
+            }
+            Ok((value, rest)) => {
+                values.push(value);
+                input = rest;
+            }
+            Err(failure) if failure.rest.len() < input.len() || values.len() < min => {
+                return Err(failure)
+            }
+            Err(_) => return Ok((values, input)),
+        }
+    }
+}
+
+/// Returns a parser that matches any number of [item], including none, separated by [sep].
+pub fn sep_by<'a, T, S>(
+    item: impl Parser<'a, T>,
+    sep: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    let items = sep_by1(item, sep);
+
+    from_fn(move |input| match items.parse(input) {
+        Ok(result) => Ok(result),
+        Err(failure) if failure.rest.len() < input.len() => Err(failure),
+        Err(_) => Ok((vec![], input)),
+    })
+}
+
+/// Returns a parser that matches at least one [item], separated by [sep]. Once a separator matches, the item after
+/// it must match too.
+pub fn sep_by1<'a, T, S>(
+    item: impl Parser<'a, T>,
+    sep: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    from_fn(move |input| {
+        let (first, mut input) = item.parse(input)?;
+        let mut values = vec![first];
+
+        while let Ok((_, rest)) = sep.parse(input) {
+            let (value, rest) = item.parse(rest)?;
+            values.push(value);
+            input = rest;
+        }
+
+        Ok((values, input))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn many1_requires_a_match_that_consumes_input() {
+        let failure = many1(opt(tag("x"))).parse("").unwrap_err();
+        assert_eq!(failure.rest, "");
+
+        let (values, rest) = many1(opt(tag("x"))).parse("xxy").unwrap();
+        assert_eq!(values, vec![Some("x"), Some("x")]);
+        assert_eq!(rest, "y");
+    }
+
+    #[test]
+    fn many_allows_no_matches() {
+        assert_eq!(many(opt(tag("x"))).parse("y"), Ok((vec![], "y")));
+        assert_eq!(many(tag("x")).parse(""), Ok((vec![], "")));
+    }
+
+    #[test]
+    fn many1_fails_without_a_match() {
+        let failure = many1(tag("x")).parse("y").unwrap_err();
+        assert_eq!(failure.rest, "y");
+        assert_eq!(failure.expected, vec!["`x`"]);
+    }
+
+    #[test]
+    fn tag_matches_a_prefix() {
+        assert_eq!(tag("ab").parse("abc"), Ok(("ab", "c")));
+
+        let failure = tag("ab").parse("ac").unwrap_err();
+        assert_eq!(failure.rest, "ac");
+        assert_eq!(failure.expected, vec!["`ab`"]);
+    }
+
+    #[test]
+    fn number_reads_a_sign_only_when_signed() {
+        assert_eq!(number::<i32>().parse("-12,"), Ok((-12, ",")));
+        assert_eq!(number::<u32>().parse("12"), Ok((12, "")));
+        assert_eq!(number::<u32>().parse("-12").unwrap_err().rest, "-12");
+        assert_eq!(
+            number::<i32>().parse("-x").unwrap_err().expected,
+            vec!["a number"]
+        );
+        assert_eq!(
+            number::<i32>().parse("").unwrap_err().expected,
+            vec!["a number"]
+        );
+    }
+
+    #[test]
+    fn number_fails_on_overflow() {
+        let failure = number::<u8>().parse("256 ").unwrap_err();
+        assert_eq!(failure.rest, "256 ");
+        assert_eq!(failure.expected, vec!["a number that fits in u8"]);
+
+        assert_eq!(number::<u8>().parse("255 "), Ok((255, " ")));
+        assert_eq!(number::<i8>().parse("-128"), Ok((-128, "")));
+    }
+
+    #[test]
+    fn alt_merges_failures_at_the_same_place() {
+        let color = alt((tag("red"), tag("green"), tag("blue")));
+
+        assert_eq!(color.parse("green!"), Ok(("green", "!")));
+
+        let failure = color.parse("pink").unwrap_err();
+        assert_eq!(failure.rest, "pink");
+        assert_eq!(failure.expected, vec!["`red`", "`green`", "`blue`"]);
+    }
+
+    #[test]
+    fn alt_keeps_the_failure_that_got_furthest() {
+        let parser = alt((preceded(tag("a"), tag("b")), tag("c")));
+
+        let failure = parser.parse("ax").unwrap_err();
+        assert_eq!(failure.rest, "x");
+        assert_eq!(failure.expected, vec!["`b`"]);
+    }
+
+    #[test]
+    fn sep_by_allows_no_items() {
+        let numbers = sep_by(number::<u32>(), tag(","));
+
+        assert_eq!(numbers.parse("1,2,3;"), Ok((vec![1, 2, 3], ";")));
+        assert_eq!(numbers.parse(";"), Ok((vec![], ";")));
+    }
+
+    #[test]
+    fn sep_by1_requires_an_item_after_each_separator() {
+        let numbers = sep_by1(number::<u32>(), tag(","));
+
+        assert_eq!(numbers.parse("1"), Ok((vec![1], "")));
+        assert_eq!(numbers.parse(";").unwrap_err().rest, ";");
+
+        let failure = numbers.parse("1,2,x").unwrap_err();
+        assert_eq!(failure.rest, "x");
+        assert_eq!(failure.expected, vec!["a number"]);
+    }
+
+    #[test]
+    fn delimited_returns_the_inner_value() {
+        let list = delimited(tag("["), sep_by(number::<i64>(), tag(", ")), tag("]"));
+
+        assert_eq!(list.parse("[1, -2]"), Ok((vec![1, -2], "")));
+        assert_eq!(list.parse("[]"), Ok((vec![], "")));
+        assert_eq!(list.parse("[1, 2").unwrap_err().expected, vec!["`]`"]);
+    }
+
+    #[test]
+    fn parse_all_locates_the_failure() {
+        let text = "3 red, 4 blue\n5 red, 6 pink";
+        let line = text.lines().nth(1).unwrap();
+
+        let cube = pair(
+            terminated(number::<u32>(), tag(" ")),
+            alt((tag("red"), tag("blue"))),
+        );
+        let error = parse_all(sep_by1(cube, tag(", ")), line)
+            .unwrap_err()
+            .locate(text);
+
+        assert_eq!(error.message(), "expected `red` or `blue`, found `p`");
+
+        let location = error.location().unwrap();
+        assert_eq!((location.line, location.column, location.width), (2, 10, 1));
+    }
+
+    #[test]
+    fn parse_all_requires_the_whole_input() {
+        let error = parse_all(number::<u32>(), "12 ").unwrap_err();
+        assert_eq!(error.message(), "expected the end of the input, found ` `");
+
+        let error = parse_all(tag("a"), "").unwrap_err();
+        assert_eq!(error.message(), "expected `a`, found the end of the input");
+    }
+
+    #[test]
+    fn parsers_can_be_shared_by_reference() {
+        let digit = from_fn(|input: &str| match input.chars().next() {
+            Some(c) if c.is_ascii_digit() => Ok((c, &input[1..])),
+            _ => Err(Failure::new(input, "a digit")),
+        });
+
+        let digits = many1(&digit);
+
+        assert_eq!(digits.parse("12a"), Ok((vec!['1', '2'], "a")));
+        assert_eq!(parse_all(&digit, "7"), Ok('7'));
+        assert_eq!(
+            parse_all(&digits, "x").unwrap_err().message(),
+            "expected a digit, found `x`"
+        );
+    }
+}
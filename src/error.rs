@@ -0,0 +1,195 @@
+use std::fmt::{self, Display};
+use std::num::{ParseFloatError, ParseIntError};
+
+/// A specialized Result type for puzzle solutions.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// An error produced while parsing or solving a puzzle, optionally located within the puzzle input.
+///
+/// An error can be attached to the slice of the input that caused it with [Error::at]. The slice is resolved to a
+/// line and column once the error reaches the harness, which knows the full input text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    message: String,
+    token: Option<(usize, usize)>,
+    location: Option<Location>,
+}
+
+/// The position of an error within the puzzle input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    /// The line number, starting from one.
+    pub line: usize,
+    /// The column number in characters, starting from one.
+    pub column: usize,
+    /// The number of characters of the offending token.
+    pub width: usize,
+    /// The text of the line.
+    pub source: String,
+}
+
+impl Error {
+    /// Creates a new Error with the given [message].
+    pub fn new(message: impl Into<String>) -> Self {
+        Error {
+            message: message.into(),
+            token: None,
+            location: None,
+        }
+    }
+
+    /// Returns the error attached to the [token], which should be a slice of the puzzle input.
+    pub fn at(mut self, token: &str) -> Self {
+        self.token = Some((token.as_ptr() as usize, token.len()));
+        self
+    }
+
+    /// Returns the error with its location resolved within the input [text], if the error is attached to a slice
+    /// of the text and has not already been located.
+    pub fn locate(mut self, text: &str) -> Self {
+        let Some((start, len)) = self.token else {
+            return self;
+        };
+
+        let text_start = text.as_ptr() as usize;
+
+        if self.location.is_some() || start < text_start || start + len > text_start + text.len() {
+            return self;
+        }
+
+        let offset = start - text_start;
+
+        let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = text[offset..].find('\n').map_or(text.len(), |i| offset + i);
+        let source = text[line_start..line_end].trim_end_matches('\r');
+
+        self.location = Some(Location {
+            line: text[..offset].matches('\n').count() + 1,
+            column: text[line_start..offset].chars().count() + 1,
+            width: text[offset..(offset + len).min(line_end)].chars().count(),
+            source: source.to_string(),
+        });
+
+        self
+    }
+
+    /// Returns the message describing the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the location of the error within the input, if known.
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    /// Returns a multi-line description of the error, including the offending line of input if known.
+    pub fn report(&self) -> String {
+        let Some(location) = &self.location else {
+            return self.to_string();
+        };
+
+        let number = location.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent = " ".repeat(location.column - 1);
+        let marker = "^".repeat(location.width.max(1));
+
+        format!(
+            "{self}\n{gutter} |\n{number} | {}\n{gutter} | {indent}{marker}",
+            location.source
+        )
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(
+                f,
+                "line {}, column {}: {}",
+                location.line, location.column, self.message
+            ),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Error::new(e.to_string())
+    }
+}
+
+impl From<ParseFloatError> for Error {
+    fn from(e: ParseFloatError) -> Self {
+        Error::new(e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_a_token_on_a_later_line() {
+        let text = "1 2\n3 4\n5 66 7";
+        let error = Error::new("bad value").at(&text[10..12]).locate(text);
+
+        let location = error.location().unwrap();
+        assert_eq!((location.line, location.column, location.width), (3, 3, 2));
+        assert_eq!(location.source, "5 66 7");
+        assert_eq!(error.to_string(), "line 3, column 3: bad value");
+    }
+
+    #[test]
+    fn counts_columns_in_characters() {
+        let text = "é→ x";
+        let start = text.find('x').unwrap();
+        let error = Error::new("bad value").at(&text[start..]).locate(text);
+
+        assert_eq!(error.location().unwrap().column, 4);
+    }
+
+    #[test]
+    fn leaves_a_token_from_another_string_unlocated() {
+        let text = String::from("1 2 3");
+        let other = text.clone();
+        let error = Error::new("bad value").at(&other[2..3]).locate(&text);
+
+        assert_eq!(error.location(), None);
+        assert_eq!(error.to_string(), "bad value");
+        assert_eq!(error.report(), "bad value");
+    }
+
+    #[test]
+    fn keeps_the_first_location() {
+        let text = "a\nb";
+        let error = Error::new("bad value")
+            .at(&text[2..])
+            .locate(text)
+            .locate(&text[2..]);
+
+        assert_eq!(error.location().unwrap().line, 2);
+    }
+
+    #[test]
+    fn reports_the_line_with_a_caret_under_the_token() {
+        let text = "x=1\ny=abc\n";
+        let error = Error::new("expected a number").at(&text[6..9]).locate(text);
+
+        assert_eq!(
+            error.report(),
+            "line 2, column 3: expected a number\n  |\n2 | y=abc\n  |   ^^^"
+        );
+    }
+
+    #[test]
+    fn reports_an_empty_token_with_one_caret() {
+        let text = "ab";
+        let error = Error::new("expected `c`").at(&text[2..]).locate(text);
+
+        assert!(error.report().ends_with("\n  |   ^"), "{}", error.report());
+    }
+}
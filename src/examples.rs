@@ -0,0 +1,141 @@
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+use crate::answers::Answers;
+use crate::Answer;
+
+/// The directory containing the example files, one `YYYY_DD.toml` per puzzle.
+pub const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/examples");
+
+/// A worked example from a puzzle description, with the expected answer to each part it applies to.
+///
+/// Examples are stored as a TOML array of tables. The input is a literal string whose final newline is removed, to
+/// match the input files:
+///
+/// ```toml
+/// [[example]]
+/// name = "sonar"
+/// part_one = 7
+/// part_two = 5
+/// input = '''
+/// 199
+/// 200
+/// '''
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: Answers,
+}
+
+/// Returns the examples for the puzzle with the specified `YYYY_DD` [name], or no examples if the puzzle does not
+/// have an example file.
+pub fn load(name: &str) -> Result<Vec<Example>, String> {
+    let path = path(name);
+
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let text = std::fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+
+    parse(&text).map_err(|e| format!("invalid examples in {}: {e}", path.display()))
+}
+
+/// Returns the examples in the TOML [text].
+pub fn parse(text: &str) -> Result<Vec<Example>, String> {
+    let table: Table = text
+        .parse()
+        .map_err(|e: toml::de::Error| e.message().to_string())?;
+
+    let Some(examples) = table.get("example") else {
+        return Ok(vec![]);
+    };
+
+    let Value::Array(examples) = examples else {
+        return Err(String::from("expected `example` to be an array of tables"));
+    };
+
+    examples.iter().enumerate().map(parse_example).collect()
+}
+
+/// Returns the path of the example file for the puzzle with the specified `YYYY_DD` [name].
+pub fn path(name: &str) -> PathBuf {
+    Path::new(EXAMPLES_DIR).join(format!("{name}.toml"))
+}
+
+fn parse_example((index, value): (usize, &Value)) -> Result<Example, String> {
+    let table = value
+        .as_table()
+        .ok_or_else(|| format!("expected example {} to be a table", index + 1))?;
+
+    let name = match table.get("name") {
+        Some(Value::String(name)) => name.clone(),
+        Some(_) => {
+            return Err(format!(
+                "expected the name of example {} to be a string",
+                index + 1
+            ))
+        }
+        None => (index + 1).to_string(),
+    };
+
+    let input = match table.get("input") {
+        Some(Value::String(input)) => input.strip_suffix('\n').unwrap_or(input).to_string(),
+        _ => return Err(format!("expected example `{name}` to have a string input")),
+    };
+
+    let answers = Answers::from_table(table).map_err(|e| format!("example `{name}`: {e}"))?;
+
+    Ok(Example {
+        name,
+        input,
+        answers,
+    })
+}
+
+/// Returns the [examples] as TOML, in the format read by [parse].
+pub fn to_toml(examples: &[Example]) -> Result<String, String> {
+    let mut text = String::new();
+
+    for example in examples {
+        if example.input.contains("'''") {
+            return Err(format!(
+                "the input of example `{}` cannot be written as a literal string",
+                example.name
+            ));
+        }
+
+        if !text.is_empty() {
+            text.push('\n');
+        }
+
+        text.push_str("[[example]]\n");
+        text.push_str(&format!("name = {}\n", Value::from(example.name.as_str())));
+
+        for (key, answer) in [
+            ("part_one", &example.answers.part_one),
+            ("part_two", &example.answers.part_two),
+        ] {
+            if let Some(answer) = answer {
+                text.push_str(&format!("{key} = {}\n", answer_toml(answer)));
+            }
+        }
+
+        text.push_str(&format!("input = '''\n{}\n'''\n", example.input));
+    }
+
+    Ok(text)
+}
+
+/// Returns the TOML value of the [answer], which is an integer if it fits and a string otherwise.
+fn answer_toml(answer: &Answer) -> String {
+    match answer {
+        Answer::Integer(n) if i64::try_from(*n).is_ok() => n.to_string(),
+        Answer::Grid(_) => format!("'''\n{}\n'''", answer.normalized()),
+        answer => Value::from(answer.normalized()).to_string(),
+    }
+}
@@ -0,0 +1,628 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use toml::{Table, Value};
+
+use crate::scrape::strip_tags;
+use crate::{Answer, Part};
+
+/// The directory containing the submission history files, one `YYYY_DD.toml` per puzzle.
+pub const HISTORY_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/history");
+
+/// The response of the puzzle site to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// The answer was not checked because another was submitted too recently.
+    Wait(Duration),
+    /// The answer was not checked because the part is already solved or not yet unlocked.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Returns the outcome described by the HTML [response] to a submission, if it is recognized.
+    pub fn parse(response: &str) -> Option<Outcome> {
+        let text = strip_tags(response);
+
+        if text.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Some(Outcome::TooHigh)
+            } else if text.contains("too low") {
+                Some(Outcome::TooLow)
+            } else {
+                Some(Outcome::Incorrect)
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Some(Outcome::Wait(
+                parse_wait(&text).unwrap_or(Duration::from_secs(60)),
+            ))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Some(Outcome::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    /// Returns how long the HTML [response] to a wrong answer asks to wait before submitting again, as in "Please wait
+    /// one minute before trying again", if it asks at all.
+    pub fn parse_retry_after(response: &str) -> Option<Duration> {
+        let text = strip_tags(response).to_lowercase();
+
+        if !text.contains("that's not the right answer") || !text.contains("before trying again") {
+            return None;
+        }
+
+        Some(parse_retry_wait(&text).unwrap_or(Duration::from_secs(60)))
+    }
+
+    /// Returns true if the outcome says whether the answer was right, rather than that it was not checked.
+    pub fn is_verdict(&self) -> bool {
+        !matches!(self, Outcome::Wait(_) | Outcome::WrongLevel)
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wait(_) => "wait",
+            Outcome::WrongLevel => "wrong level",
+        }
+    }
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Wait(duration) => write!(f, "wait {}", format_duration(*duration)),
+            outcome => write!(f, "{}", outcome.key()),
+        }
+    }
+}
+
+/// A single submission of an answer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    pub part: Part,
+    pub answer: Answer,
+    pub outcome: Outcome,
+    /// How long the site asked to wait before the next submission, after a wrong answer.
+    pub retry_after: Option<Duration>,
+    /// The time of the submission, in seconds since the Unix epoch.
+    pub time: u64,
+}
+
+/// Every answer submitted for a puzzle, oldest first.
+///
+/// The history is stored as TOML, with one table per attempt:
+///
+/// ```toml
+/// [[attempt]]
+/// answer = "1791"
+/// outcome = "too low"
+/// part = 1
+/// retry_after = 60
+/// time = 1701406800
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Returns the history of the puzzle with the specified `YYYY_DD` [name], which is empty if nothing has been
+    /// submitted.
+    pub fn load(name: &str) -> Result<History, String> {
+        let path = path(name);
+
+        if !path.exists() {
+            return Ok(History::default());
+        }
+
+        let text = std::fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+
+        History::parse(&text).map_err(|e| format!("invalid history in {}: {e}", path.display()))
+    }
+
+    /// Returns the history in the TOML [text].
+    pub fn parse(text: &str) -> Result<History, String> {
+        let table: Table = text
+            .parse()
+            .map_err(|e: toml::de::Error| e.message().to_string())?;
+
+        let attempts = match table.get("attempt") {
+            None => vec![],
+            Some(Value::Array(values)) => {
+                values.iter().map(attempt_value).collect::<Result<_, _>>()?
+            }
+            Some(value) => {
+                return Err(format!(
+                    "expected an array of attempts, found {}",
+                    value.type_str()
+                ))
+            }
+        };
+
+        Ok(History { attempts })
+    }
+
+    /// Writes the history of the puzzle with the specified `YYYY_DD` [name].
+    pub fn save(&self, name: &str) -> Result<(), String> {
+        let path = path(name);
+
+        std::fs::create_dir_all(HISTORY_DIR)
+            .map_err(|e| format!("failed to create {HISTORY_DIR}: {e}"))?;
+        std::fs::write(&path, self.to_toml())
+            .map_err(|e| format!("failed to write {}: {e}", path.display()))
+    }
+
+    /// Returns the history as TOML.
+    pub fn to_toml(&self) -> String {
+        let attempts = self
+            .attempts
+            .iter()
+            .map(attempt_table)
+            .map(Value::Table)
+            .collect();
+
+        let mut table = Table::new();
+        table.insert(String::from("attempt"), Value::Array(attempts));
+
+        toml::to_string(&table).expect("history is always serializable")
+    }
+
+    /// Records an [attempt].
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Returns the reason the [answer] to the [part] should not be submitted at the specified [time], if the history
+    /// already shows it to be wrong or the site is still asking to wait.
+    pub fn refusal(&self, part: Part, answer: &Answer, time: u64) -> Option<String> {
+        let attempts = || self.attempts.iter().filter(|a| a.part == part);
+
+        if let Some(correct) = attempts().find(|a| a.outcome == Outcome::Correct) {
+            return Some(format!("{part} was already solved with {}", correct.answer));
+        }
+
+        if let Some(wrong) = attempts().find(|a| a.outcome.is_verdict() && a.answer.matches(answer))
+        {
+            return Some(format!(
+                "{answer} was already submitted and was {}",
+                wrong.outcome
+            ));
+        }
+
+        if let Some(n) = integer(answer) {
+            let bound = |outcome| {
+                attempts()
+                    .filter(move |a| a.outcome == outcome)
+                    .filter_map(|a| integer(&a.answer))
+            };
+
+            if let Some(high) = bound(Outcome::TooHigh).min().filter(|&high| n >= high) {
+                return Some(format!("{n} is not below {high}, which was too high"));
+            }
+
+            if let Some(low) = bound(Outcome::TooLow).max().filter(|&low| n <= low) {
+                return Some(format!("{n} is not above {low}, which was too low"));
+            }
+        }
+
+        let retry = self.attempts.iter().filter_map(|a| match a.outcome {
+            Outcome::Wait(duration) => Some(a.time + duration.as_secs()),
+            _ => Some(a.time + a.retry_after?.as_secs()),
+        });
+
+        if let Some(retry) = retry.max().filter(|&retry| retry > time) {
+            let left = Duration::from_secs(retry - time);
+            return Some(format!(
+                "the site asked to wait, try again in {}",
+                format_duration(left)
+            ));
+        }
+
+        None
+    }
+}
+
+/// Returns the path of the history file for the puzzle with the specified `YYYY_DD` [name].
+pub fn path(name: &str) -> PathBuf {
+    Path::new(HISTORY_DIR).join(format!("{name}.toml"))
+}
+
+/// Returns the current time in seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Returns the [answer] as an integer, whether it was computed as one or read back from the history as text.
+fn integer(answer: &Answer) -> Option<i128> {
+    answer.normalized().parse().ok()
+}
+
+fn attempt_table(attempt: &Attempt) -> Table {
+    let part = match attempt.part {
+        Part::One => 1,
+        Part::Two => 2,
+    };
+
+    let mut table = Table::new();
+    table.insert(String::from("part"), Value::Integer(part));
+    table.insert(
+        String::from("answer"),
+        Value::String(attempt.answer.normalized()),
+    );
+    table.insert(
+        String::from("outcome"),
+        Value::String(attempt.outcome.key().to_string()),
+    );
+
+    if let Outcome::Wait(duration) = attempt.outcome {
+        table.insert(
+            String::from("wait"),
+            Value::Integer(duration.as_secs() as i64),
+        );
+    }
+
+    if let Some(retry_after) = attempt.retry_after {
+        table.insert(
+            String::from("retry_after"),
+            Value::Integer(retry_after.as_secs() as i64),
+        );
+    }
+
+    table.insert(String::from("time"), Value::Integer(attempt.time as i64));
+    table
+}
+
+fn attempt_value(value: &Value) -> Result<Attempt, String> {
+    let table = value
+        .as_table()
+        .ok_or("expected each attempt to be a table")?;
+
+    let integer = |key: &str| {
+        table
+            .get(key)
+            .and_then(Value::as_integer)
+            .ok_or_else(|| format!("expected an integer `{key}` in each attempt"))
+    };
+
+    let string = |key: &str| {
+        table
+            .get(key)
+            .and_then(Value::as_str)
+            .ok_or_else(|| format!("expected a string `{key}` in each attempt"))
+    };
+
+    let part = match integer("part")? {
+        1 => Part::One,
+        2 => Part::Two,
+        n => return Err(format!("invalid part {n}")),
+    };
+
+    let outcome = match string("outcome")? {
+        "correct" => Outcome::Correct,
+        "incorrect" => Outcome::Incorrect,
+        "too high" => Outcome::TooHigh,
+        "too low" => Outcome::TooLow,
+        "wait" => Outcome::Wait(Duration::from_secs(integer("wait")? as u64)),
+        "wrong level" => Outcome::WrongLevel,
+        s => return Err(format!("invalid outcome `{s}`")),
+    };
+
+    let retry_after = match table.get("retry_after") {
+        None => None,
+        Some(_) => Some(Duration::from_secs(integer("retry_after")? as u64)),
+    };
+
+    Ok(Attempt {
+        part,
+        answer: Answer::from_text(string("answer")?),
+        outcome,
+        retry_after,
+        time: integer("time")? as u64,
+    })
+}
+
+/// Returns the duration in a message like "You have 4m 32s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+
+    for token in text[start..end].split_whitespace() {
+        let (n, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+
+        seconds += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Returns the duration in a lowercase message like "please wait 5 minutes before trying again".
+fn parse_retry_wait(text: &str) -> Option<Duration> {
+    let start = text.find("wait ")? + "wait ".len();
+    let end = start + text[start..].find(" before trying again")?;
+
+    let (n, unit) = text[start..end].split_once(' ')?;
+
+    let n = match n {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+
+    match unit {
+        "minute" | "minutes" => Some(Duration::from_secs(n * 60)),
+        "second" | "seconds" => Some(Duration::from_secs(n)),
+        _ => None,
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+
+    match (seconds / 60, seconds % 60) {
+        (0, s) => format!("{s}s"),
+        (m, s) => format!("{m}m {s}s"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(message: &str) -> String {
+        format!("<!DOCTYPE html><html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    fn attempt(part: Part, answer: i128, outcome: Outcome, time: u64) -> Attempt {
+        Attempt {
+            part,
+            answer: Answer::Integer(answer),
+            outcome,
+            retry_after: None,
+            time,
+        }
+    }
+
+    fn history(attempts: Vec<Attempt>) -> History {
+        History { attempts }
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        let correct = response("That's the right answer! You are <span class=\"day-success\">one gold star</span> closer.");
+        let high = response(
+            "That's not the right answer; your answer is too high. Please wait one minute.",
+        );
+        let low = response("That's not the right answer; your answer is too low.");
+        let wrong = response(
+            "That's not the right answer. If you're stuck, make sure you're using the full input.",
+        );
+
+        assert_eq!(Outcome::parse(&correct), Some(Outcome::Correct));
+        assert_eq!(Outcome::parse(&high), Some(Outcome::TooHigh));
+        assert_eq!(Outcome::parse(&low), Some(Outcome::TooLow));
+        assert_eq!(Outcome::parse(&wrong), Some(Outcome::Incorrect));
+    }
+
+    #[test]
+    fn parses_the_wait_after_a_wrong_answer() {
+        let minute = response("That's not the right answer; your answer is too high. Please wait one minute before trying again.");
+        let minutes = response("That's not the right answer. Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.");
+        let unreadable =
+            response("That's not the right answer. Please wait a moment before trying again.");
+        let correct = response("That's the right answer!");
+        let recent = response("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 30s left to wait.");
+
+        assert_eq!(
+            Outcome::parse_retry_after(&minute),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            Outcome::parse_retry_after(&minutes),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            Outcome::parse_retry_after(&unreadable),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(Outcome::parse_retry_after(&correct), None);
+        assert_eq!(Outcome::parse_retry_after(&recent), None);
+    }
+
+    #[test]
+    fn parses_waits_and_wrong_levels() {
+        let wait = response("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 5s left to wait.");
+        let seconds = response("You gave an answer too recently. You have 42s left to wait.");
+        let level =
+            response("You don't seem to be solving the right level.  Did you already complete it?");
+
+        assert_eq!(
+            Outcome::parse(&wait),
+            Some(Outcome::Wait(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Outcome::parse(&seconds),
+            Some(Outcome::Wait(Duration::from_secs(42)))
+        );
+        assert_eq!(Outcome::parse(&level), Some(Outcome::WrongLevel));
+        assert_eq!(Outcome::parse(&response("Something else")), None);
+    }
+
+    #[test]
+    fn parses_an_unreadable_wait_as_a_minute() {
+        let wait = response("You gave an answer too recently. You have a while left to wait.");
+        assert_eq!(
+            Outcome::parse(&wait),
+            Some(Outcome::Wait(Duration::from_secs(60)))
+        );
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let too_low = Attempt {
+            retry_after: Some(Duration::from_secs(60)),
+            ..attempt(Part::One, 1791, Outcome::TooLow, 100)
+        };
+
+        let history = history(vec![
+            too_low,
+            attempt(Part::One, 1800, Outcome::Wait(Duration::from_secs(65)), 160),
+            attempt(Part::Two, 12, Outcome::WrongLevel, 200),
+            attempt(Part::One, 1795, Outcome::Correct, 300),
+        ]);
+
+        let parsed = History::parse(&history.to_toml()).unwrap();
+
+        assert_eq!(parsed.attempts.len(), 4);
+
+        for (parsed, attempt) in parsed.attempts.iter().zip(&history.attempts) {
+            assert_eq!(parsed.part, attempt.part);
+            assert!(parsed.answer.matches(&attempt.answer));
+            assert_eq!(parsed.outcome, attempt.outcome);
+            assert_eq!(parsed.retry_after, attempt.retry_after);
+            assert_eq!(parsed.time, attempt.time);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_toml() {
+        assert!(History::parse("attempt = 3").is_err());
+        assert!(History::parse(
+            "[[attempt]]\npart = 3\nanswer = \"1\"\noutcome = \"correct\"\ntime = 0"
+        )
+        .is_err());
+        assert!(History::parse(
+            "[[attempt]]\npart = 1\nanswer = \"1\"\noutcome = \"maybe\"\ntime = 0"
+        )
+        .is_err());
+        assert_eq!(History::parse("").unwrap(), History::default());
+    }
+
+    #[test]
+    fn refuses_a_solved_part() {
+        let history = history(vec![attempt(Part::One, 5, Outcome::Correct, 0)]);
+
+        assert!(history
+            .refusal(Part::One, &Answer::Integer(6), 1000)
+            .is_some());
+        assert!(history
+            .refusal(Part::Two, &Answer::Integer(6), 1000)
+            .is_none());
+    }
+
+    #[test]
+    fn refuses_an_answer_already_judged_wrong() {
+        let history = history(vec![attempt(Part::One, 5, Outcome::Incorrect, 0)]);
+
+        assert!(history
+            .refusal(Part::One, &Answer::Integer(5), 1000)
+            .is_some());
+        assert!(history
+            .refusal(Part::One, &Answer::from_text("5"), 1000)
+            .is_some());
+        assert!(history
+            .refusal(Part::One, &Answer::Integer(6), 1000)
+            .is_none());
+    }
+
+    #[test]
+    fn allows_resubmitting_an_answer_that_was_not_checked() {
+        let history = history(vec![
+            attempt(Part::One, 5, Outcome::WrongLevel, 0),
+            attempt(Part::One, 6, Outcome::Wait(Duration::from_secs(60)), 0),
+        ]);
+
+        assert!(history
+            .refusal(Part::One, &Answer::Integer(5), 1000)
+            .is_none());
+        assert!(history
+            .refusal(Part::One, &Answer::Integer(6), 1000)
+            .is_none());
+    }
+
+    #[test]
+    fn refuses_answers_outside_the_known_bounds() {
+        let history = history(vec![
+            attempt(Part::One, 100, Outcome::TooHigh, 0),
+            attempt(Part::One, 80, Outcome::TooHigh, 0),
+            attempt(Part::One, 10, Outcome::TooLow, 0),
+        ]);
+
+        assert!(history
+            .refusal(Part::One, &Answer::Integer(80), 1000)
+            .is_some());
+        assert!(history
+            .refusal(Part::One, &Answer::Integer(90), 1000)
+            .is_some());
+        assert!(history
+            .refusal(Part::One, &Answer::Integer(10), 1000)
+            .is_some());
+        assert!(history
+            .refusal(Part::One, &Answer::Integer(5), 1000)
+            .is_some());
+        assert!(history
+            .refusal(Part::One, &Answer::Integer(50), 1000)
+            .is_none());
+        assert!(history
+            .refusal(Part::Two, &Answer::Integer(90), 1000)
+            .is_none());
+    }
+
+    #[test]
+    fn refuses_during_a_wait() {
+        let history = history(vec![attempt(
+            Part::One,
+            5,
+            Outcome::Wait(Duration::from_secs(65)),
+            1000,
+        )]);
+
+        let refusal = history
+            .refusal(Part::One, &Answer::Integer(6), 1030)
+            .unwrap();
+        assert!(refusal.contains("35s"), "{refusal}");
+
+        assert!(history
+            .refusal(Part::Two, &Answer::Integer(6), 1030)
+            .is_some());
+        assert!(history
+            .refusal(Part::One, &Answer::Integer(6), 1065)
+            .is_none());
+    }
+
+    #[test]
+    fn refuses_until_the_wait_after_a_wrong_answer() {
+        let history = history(vec![Attempt {
+            retry_after: Some(Duration::from_secs(60)),
+            ..attempt(Part::One, 5, Outcome::TooHigh, 1000)
+        }]);
+
+        let refusal = history
+            .refusal(Part::One, &Answer::Integer(4), 1030)
+            .unwrap();
+        assert!(refusal.contains("30s"), "{refusal}");
+
+        assert!(history
+            .refusal(Part::One, &Answer::Integer(4), 1060)
+            .is_none());
+    }
+}
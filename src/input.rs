@@ -0,0 +1,247 @@
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The directory containing the puzzle input files, resolved at compile time so that it does not depend on the
+/// working directory.
+pub const INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input");
+
+/// The location of a puzzle input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    /// The input file of the puzzle in [INPUT_DIR].
+    Default,
+    /// The file at the given path.
+    Path(PathBuf),
+    /// The standard input.
+    Stdin,
+}
+
+impl InputSource {
+    /// Returns the input source described by a command-line [arg], where `-` is the standard input.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+
+    /// Returns the text of the input for the puzzle with the specified `YYYY_DD` [name].
+    pub fn read(&self, name: &str) -> Result<String, String> {
+        match self {
+            InputSource::Default => read_file(&default_path(name)),
+            InputSource::Path(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin()
+                    .read_to_string(&mut text)
+                    .map_err(|e| format!("failed to read stdin: {e}"))?;
+                Ok(text)
+            }
+        }
+    }
+
+    /// Returns the text of the input for the puzzle with the specified `YYYY_DD` [name], normalized by [check], with
+    /// the problems found in it. An empty input is an error, since no solution can do anything useful with it.
+    ///
+    /// The input files are stored without a final newline, so only an input from elsewhere is expected to have one.
+    pub fn read_checked(&self, name: &str) -> Result<(String, Vec<Diagnostic>), String> {
+        let text = self.read(name)?;
+        let (text, mut diagnostics) = check(&text);
+
+        if *self == InputSource::Default {
+            diagnostics.retain(|d| *d != Diagnostic::MissingFinalNewline);
+        }
+
+        if diagnostics.contains(&Diagnostic::Empty) {
+            let source = match self {
+                InputSource::Default => default_path(name).display().to_string(),
+                InputSource::Path(path) => path.display().to_string(),
+                InputSource::Stdin => String::from("stdin"),
+            };
+
+            return Err(format!("{source} is empty"));
+        }
+
+        Ok((text, diagnostics))
+    }
+}
+
+/// A problem found in an input, which usually means that it was saved by an editor or copied from a browser rather
+/// than downloaded.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Diagnostic {
+    /// The input has no text other than whitespace.
+    Empty,
+    /// The lines end with CRLF, which was normalized to LF.
+    CrLf,
+    /// The input does not end with a newline, as a downloaded input does, so it may have been truncated.
+    MissingFinalNewline,
+    /// The given number of lines end with spaces or tabs, starting at the given line.
+    TrailingWhitespace { lines: usize, first: usize },
+    /// The given number of characters are not ASCII, starting at the given line and column.
+    NonAscii {
+        count: usize,
+        line: usize,
+        column: usize,
+    },
+}
+
+impl Diagnostic {
+    /// Returns true if the problem was fixed by [check], rather than left in the input.
+    pub fn is_normalized(&self) -> bool {
+        matches!(self, Diagnostic::CrLf)
+    }
+
+    /// Returns the label the problem is reported with: a note if it was fixed, or a warning otherwise.
+    pub fn label(&self) -> &'static str {
+        if self.is_normalized() {
+            "note"
+        } else {
+            "warning"
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::Empty => write!(f, "the input is empty"),
+            Diagnostic::CrLf => write!(f, "converted CRLF line endings to LF"),
+            Diagnostic::MissingFinalNewline => {
+                write!(
+                    f,
+                    "the input does not end with a newline, it may be truncated"
+                )
+            }
+            Diagnostic::TrailingWhitespace { lines, first } => {
+                write!(
+                    f,
+                    "{lines} lines end with whitespace, starting at line {first}"
+                )
+            }
+            Diagnostic::NonAscii {
+                count,
+                line,
+                column,
+            } => write!(
+                f,
+                "{count} non-ASCII characters, starting at line {line}, column {column}"
+            ),
+        }
+    }
+}
+
+/// Returns the [text] of an input with its line endings normalized, and every problem found in it.
+///
+/// Only the line endings are changed, as the input files are stored with LF and without a final newline, so the final
+/// newline of a downloaded input is removed without comment. An input without one is reported, as it may have been
+/// cut short when copied. Trailing whitespace and non-ASCII characters may be meaningful to a puzzle, so they are
+/// reported but left in place.
+pub fn check(text: &str) -> (String, Vec<Diagnostic>) {
+    let mut diagnostics = vec![];
+
+    let mut text = if text.contains("\r\n") {
+        diagnostics.push(Diagnostic::CrLf);
+        text.replace("\r\n", "\n")
+    } else {
+        text.to_string()
+    };
+
+    if text.trim().is_empty() {
+        diagnostics.push(Diagnostic::Empty);
+        return (text, diagnostics);
+    }
+
+    if text.ends_with('\n') {
+        text.pop();
+    } else {
+        diagnostics.push(Diagnostic::MissingFinalNewline);
+    }
+
+    let trailing: Vec<usize> = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.ends_with([' ', '\t']))
+        .map(|(index, _)| index + 1)
+        .collect();
+
+    if let Some(&first) = trailing.first() {
+        diagnostics.push(Diagnostic::TrailingWhitespace {
+            lines: trailing.len(),
+            first,
+        });
+    }
+
+    let non_ascii: Vec<(usize, usize)> = text
+        .lines()
+        .enumerate()
+        .flat_map(|(index, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|(_, c)| !c.is_ascii())
+                .map(move |(column, _)| (index + 1, column + 1))
+        })
+        .collect();
+
+    if let Some(&(line, column)) = non_ascii.first() {
+        diagnostics.push(Diagnostic::NonAscii {
+            count: non_ascii.len(),
+            line,
+            column,
+        });
+    }
+
+    (text, diagnostics)
+}
+
+/// Returns the path of the input file for the puzzle with the specified `YYYY_DD` [name].
+pub fn default_path(name: &str) -> PathBuf {
+    Path::new(INPUT_DIR).join(format!("{name}.txt"))
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("failed to read {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removes_the_final_newline_of_a_downloaded_input() {
+        assert_eq!(check("1\n2\n"), (String::from("1\n2"), vec![]));
+    }
+
+    #[test]
+    fn reports_a_missing_final_newline() {
+        assert_eq!(
+            check("1\n2"),
+            (String::from("1\n2"), vec![Diagnostic::MissingFinalNewline])
+        );
+    }
+
+    #[test]
+    fn normalizes_crlf_and_reports_other_problems() {
+        let (text, diagnostics) = check("a \r\nb\u{e9}\r\n");
+
+        assert_eq!(text, "a \nb\u{e9}");
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic::CrLf,
+                Diagnostic::TrailingWhitespace { lines: 1, first: 1 },
+                Diagnostic::NonAscii {
+                    count: 1,
+                    line: 2,
+                    column: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_an_empty_input() {
+        assert!(check(" \n").1.contains(&Diagnostic::Empty));
+    }
+}
@@ -1,21 +1,92 @@
+pub mod answer;
+pub mod answers;
 pub mod array;
+pub mod cli;
+pub mod client;
+pub mod combinator;
+pub mod error;
+pub mod examples;
+pub mod history;
+pub mod input;
 pub mod parse;
+pub mod scrape;
+pub mod solution;
+pub mod testing;
 
+pub use answer::Answer;
+pub use aoc_derive::AocParse;
+pub use error::{Error, Result};
 pub use parse::*;
+pub use solution::{Day, Input, Part, Solution};
 
+/// Defines the solution of the current `src/bin/YYYY_DD.rs` file, the `main` that runs it, and tests that check it
+/// against the recorded examples and answers.
+///
+/// The parts either take the puzzle text directly:
+///
+/// ```ignore
+/// aoc!("Sonar Sweep", part_one, part_two);
+/// ```
+///
+/// or take a reference to the value returned by a shared parse step:
+///
+/// ```ignore
+/// aoc!("Smoke Basin", parse_input => part_one, part_two);
+/// ```
+///
+/// Each function may return its value directly, or as a [Result] so that invalid input is reported as an [Error].
 #[macro_export]
 macro_rules! aoc {
-    ($part_one:ident, $part_two:ident) => {
-        fn main() {
-            let stem = std::path::Path::new(file!()).file_stem().unwrap();
-            let path = format!("src/input/{}.txt", stem.to_str().unwrap());
-            let text = std::fs::read_to_string(path).unwrap();
-            println!("{}", $part_one(&text));
-            println!("{}", $part_two(&text));
+    ($title:literal, $part_one:ident, $part_two:ident) => {
+        $crate::aoc!(@main $crate::Day::from_text(file!(), $title, $part_one, $part_two));
+    };
+
+    ($title:literal, $parse:ident => $part_one:ident, $part_two:ident) => {
+        $crate::aoc!(@main $crate::Day::new(file!(), $title, $parse, $part_one, $part_two));
+    };
+
+    (@main $solution:expr) => {
+        /// Returns the solution defined in this file, used by the `aoc` runner.
+        #[allow(dead_code)]
+        pub fn solution() -> Box<dyn $crate::Solution> {
+            Box::new($solution)
+        }
+
+        #[allow(dead_code)]
+        fn main() -> std::process::ExitCode {
+            $crate::cli::main(solution().as_ref())
+        }
+
+        #[cfg(test)]
+        mod tests {
+            #[test]
+            fn examples() {
+                $crate::testing::check_examples(super::solution().as_ref());
+            }
+
+            #[test]
+            fn part_one() {
+                $crate::testing::check_answer(super::solution().as_ref(), $crate::Part::One);
+            }
+
+            #[test]
+            fn part_two() {
+                $crate::testing::check_answer(super::solution().as_ref(), $crate::Part::Two);
+            }
         }
     };
 }
 
+/// Defines `solutions()`, returning every solution in `src/bin` ordered by year and day.
+///
+/// The registry is generated by the build script, so this is only usable from targets of this package.
+#[macro_export]
+macro_rules! registry {
+    () => {
+        include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+    };
+}
+
 pub fn select<T>(condition: bool, a: T, b: T) -> T {
     if condition {
         a
@@ -1,21 +1,601 @@
-use itertools::Itertools;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
 
-/// Returns the result of parsing [s] as a u32.
-pub fn parse_u32(s: &str) -> Option<u32> {
-    s.parse().ok()
+use crate::error::{Error, Result};
+
+/// Returns the result of parsing [s], with an error attached to [s] if it is invalid.
+///
+/// A type whose `FromStr` already returns an [Error], such as one that derives `AocParse`, is better parsed with
+/// `str::parse`, which keeps its own error pointing within [s] rather than at the whole of it.
+pub fn parse_value<T: FromStr>(s: &str) -> Result<T>
+where
+    T::Err: Display,
+{
+    s.parse()
+        .map_err(|e| Error::new(format!("invalid value `{s}`: {e}")).at(s))
+}
+
+/// Returns each whitespace-separated value in [s].
+pub fn parse_list<T: FromStr>(s: &str) -> Result<Vec<T>>
+where
+    T::Err: Display,
+{
+    s.split_whitespace().map(parse_value).collect()
+}
+
+/// Returns each value in [s] separated by [sep], ignoring any whitespace around each value.
+pub fn parse_list_by<T: FromStr>(s: &str, sep: &str) -> Result<Vec<T>>
+where
+    T::Err: Display,
+{
+    s.split(sep).map(str::trim).map(parse_value).collect()
+}
+
+/// Returns the whitespace-separated values in [s], which must number exactly [N].
+pub fn parse_array<T: FromStr, const N: usize>(s: &str) -> Result<[T; N]>
+where
+    T::Err: Display,
+{
+    into_array(s, parse_list(s)?)
+}
+
+/// Returns the values in [s] separated by [sep], which must number exactly [N].
+pub fn parse_array_by<T: FromStr, const N: usize>(s: &str, sep: &str) -> Result<[T; N]>
+where
+    T::Err: Display,
+{
+    into_array(s, parse_list_by(s, sep)?)
+}
+
+fn into_array<T, const N: usize>(s: &str, values: Vec<T>) -> Result<[T; N]> {
+    let len = values.len();
+
+    values
+        .try_into()
+        .map_err(|_| Error::new(format!("expected {N} values, found {len}")).at(s))
+}
+
+/// A primitive integer type that can be extracted from text.
+pub trait Integer: FromStr<Err = std::num::ParseIntError> {
+    /// True if the type can hold negative values, in which case a `-` directly before the digits is its sign.
+    const SIGNED: bool;
+}
+
+macro_rules! impl_integer {
+    ($signed:literal, $($t:ty),*) => {
+        $(impl Integer for $t {
+            const SIGNED: bool = $signed;
+        })*
+    };
+}
+
+impl_integer!(false, u8, u16, u32, u64, u128, usize);
+impl_integer!(true, i8, i16, i32, i64, i128, isize);
+
+/// An iterator over the integers in a text, created by [ints], [uints] or [signed_ints].
+///
+/// Each integer is parsed from a slice of the text, so nothing is allocated. An integer too large for [T] is an error
+/// attached to its digits, so the iterator is usually collected into a `Result`:
+///
+/// ```ignore
+/// let numbers: Vec<u32> = ints(line).collect::<Result<_>>()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ints<'a, T> {
+    rest: &'a str,
+    signed: bool,
+    integer: PhantomData<T>,
+}
+
+impl<'a, T: Integer> Ints<'a, T> {
+    /// Returns the text of the next integer, without parsing it.
+    fn next_token(&mut self) -> Option<&'a str> {
+        let bytes = self.rest.as_bytes();
+
+        let digit = bytes.iter().position(u8::is_ascii_digit)?;
+
+        let start = match digit {
+            1.. if self.signed && bytes[digit - 1] == b'-' => digit - 1,
+            _ => digit,
+        };
+
+        let end = bytes[digit..]
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .map_or(bytes.len(), |n| digit + n);
+
+        let token = &self.rest[start..end];
+        self.rest = &self.rest[end..];
+
+        Some(token)
+    }
+}
+
+impl<T: Integer> Iterator for Ints<'_, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        self.next_token().map(parse_value)
+    }
+}
+
+/// Returns an iterator over the integers in [s], ignoring any other text. A `-` before the digits is a sign only if
+/// [T] is signed, so `x=-3` is `-3` as an i32 and `3` as a u32.
+pub fn ints<T: Integer>(s: &str) -> Ints<'_, T> {
+    Ints {
+        rest: s,
+        signed: T::SIGNED,
+        integer: PhantomData,
+    }
+}
+
+/// Returns an iterator over the integers in [s], ignoring any other text including `-`, so `1-3` is `1` and `3`.
+pub fn uints<T: Integer>(s: &str) -> Ints<'_, T> {
+    Ints {
+        rest: s,
+        signed: false,
+        integer: PhantomData,
+    }
+}
+
+/// Returns an iterator over the integers in [s], ignoring any other text, where a `-` before the digits is always a
+/// sign, so `1-3` is `1` and `-3`.
+pub fn signed_ints<T: Integer>(s: &str) -> Ints<'_, T> {
+    Ints {
+        rest: s,
+        signed: true,
+        integer: PhantomData,
+    }
+}
+
+/// A fixed number of integers that can be extracted together by [parse_ints].
+pub trait FromInts: Sized {
+    /// Returns the value built from the integers in [s], which must contain exactly as many as the value holds.
+    fn from_ints(s: &str) -> Result<Self>;
+}
+
+impl<T: Integer, const N: usize> FromInts for [T; N] {
+    fn from_ints(s: &str) -> Result<Self> {
+        let mut tokens = ints::<T>(s);
+        let mut values = Vec::with_capacity(N);
+
+        while let Some(token) = tokens.next_token() {
+            values.push(parse_value(token)?);
+        }
+
+        into_array(s, values)
+    }
+}
+
+macro_rules! impl_from_ints {
+    ($n:literal, $($t:ident),*) => {
+        impl<$($t: Integer),*> FromInts for ($($t,)*) {
+            fn from_ints(s: &str) -> Result<Self> {
+                let mut rest = s;
+
+                let value = ($(
+                    {
+                        let mut tokens = ints::<$t>(rest);
+                        let token = tokens.next_token().ok_or_else(|| too_few_ints(s, $n))?;
+                        rest = tokens.rest;
+                        parse_value::<$t>(token)?
+                    },
+                )*);
+
+                match count_ints(rest) {
+                    0 => Ok(value),
+                    more => Err(Error::new(format!("expected {} integers, found {}", $n, $n + more)).at(s)),
+                }
+            }
+        }
+    };
+}
+
+impl_from_ints!(1, A);
+impl_from_ints!(2, A, B);
+impl_from_ints!(3, A, B, C);
+impl_from_ints!(4, A, B, C, D);
+impl_from_ints!(5, A, B, C, D, E);
+impl_from_ints!(6, A, B, C, D, E, F);
+
+fn too_few_ints(s: &str, n: usize) -> Error {
+    Error::new(format!("expected {n} integers, found {}", count_ints(s))).at(s)
+}
+
+/// Returns the number of integers in [s], without parsing them.
+fn count_ints(s: &str) -> usize {
+    let mut tokens = uints::<u8>(s);
+    std::iter::from_fn(|| tokens.next_token()).count()
+}
+
+/// Returns the array or tuple of integers in [s], ignoring any other text, such as `(x, y)` from `x=3, y=-4`.
+pub fn parse_ints<T: FromInts>(s: &str) -> Result<T> {
+    T::from_ints(s)
+}
+
+/// An iterator over the blank-line separated paragraphs of a text, created by [paragraphs].
+#[derive(Debug, Clone)]
+pub struct Paragraphs<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Paragraphs<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while let Some((line, after)) = split_line(self.rest) {
+            if !line.trim().is_empty() {
+                break;
+            }
+
+            self.rest = after;
+        }
+
+        let start = self.rest;
+        let mut len = 0;
+
+        while let Some((line, after)) = split_line(self.rest) {
+            if line.trim().is_empty() {
+                break;
+            }
+
+            len = line.as_ptr() as usize - start.as_ptr() as usize + line.len();
+            self.rest = after;
+        }
+
+        (len > 0).then(|| &start[..len])
+    }
+}
+
+/// Returns the first line of [s] and the text after it, or nothing if [s] is empty.
+fn split_line(s: &str) -> Option<(&str, &str)> {
+    match s.find('\n') {
+        _ if s.is_empty() => None,
+        Some(i) => Some((&s[..i], &s[i + 1..])),
+        None => Some((s, "")),
+    }
+}
+
+/// Returns an iterator over the paragraphs of [s], which are separated by one or more blank lines. Each paragraph is
+/// a slice of [s] without its final newline, so errors attached to it point into the input.
+pub fn paragraphs(s: &str) -> Paragraphs<'_> {
+    Paragraphs { rest: s }
+}
+
+/// A paragraph whose first line is a header, such as `seed-to-soil map:`, describing the lines that follow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Section<'a> {
+    /// The first line of the paragraph.
+    pub header: &'a str,
+    /// The lines after the header, which are empty if the paragraph is a single line.
+    pub body: &'a str,
+}
+
+impl<'a> Section<'a> {
+    /// Returns the section made of the [paragraph], split after its first line.
+    pub fn new(paragraph: &'a str) -> Self {
+        match paragraph.split_once('\n') {
+            Some((header, body)) => Section { header, body },
+            None => Section {
+                header: paragraph,
+                body: &paragraph[paragraph.len()..],
+            },
+        }
+    }
+
+    /// Returns the header without its final `:`, as in `seed-to-soil map`.
+    pub fn title(&self) -> &'a str {
+        self.header.trim_end().trim_end_matches(':')
+    }
+}
+
+/// Returns an iterator over the paragraphs of [s] as sections, each with a header line.
+pub fn sections(s: &str) -> impl Iterator<Item = Section<'_>> {
+    paragraphs(s).map(Section::new)
+}
+
+/// Returns the text matched by each `{}` placeholder of the [template] in [s], where the rest of the template must
+/// match [s] exactly. Each placeholder matches as little text as possible, up to the next literal part of the
+/// template, except the last which matches up to the literal part that ends the template.
+///
+/// The error for a mismatch points at the text in [s] where the expected part of the template was not found.
+///
+/// Panics if the [template] does not have exactly [count] placeholders, or has two placeholders with nothing
+/// between them, since neither can match unambiguously.
+pub fn scan_captures<'a>(template: &str, s: &'a str, count: usize) -> Result<Vec<&'a str>> {
+    let literals: Vec<&str> = template.split("{}").collect();
+
+    assert_eq!(
+        literals.len() - 1,
+        count,
+        "the template `{template}` must have {count} placeholders"
+    );
+
+    assert!(
+        literals[1..count.max(1)].iter().all(|l| !l.is_empty()),
+        "the template `{template}` has placeholders with nothing between them"
+    );
+
+    let mut rest = expect_literal(s, literals[0])?;
+    let mut captures = Vec::with_capacity(count);
+
+    for (index, literal) in literals[1..].iter().enumerate() {
+        let last = index == count - 1;
+
+        let end = if last {
+            rest.len() - common_suffix_len(rest, literal)
+        } else {
+            rest.find(literal)
+                .ok_or_else(|| Error::new(format!("expected `{literal}`")).at(rest))?
+        };
+
+        let capture = &rest[..end];
+
+        if capture.is_empty() {
+            return Err(Error::new("expected a value").at(&rest[..next_char_len(rest)]));
+        }
+
+        captures.push(capture);
+        rest = expect_literal(&rest[end..], literal)?;
+    }
+
+    if !rest.is_empty() {
+        return Err(Error::new(format!("unexpected `{rest}`")).at(rest));
+    }
+
+    Ok(captures)
 }
 
-/// Returns a new vector containing each space-delimited u32 in [s].
-pub fn parse_u32_list(s: &str) -> Vec<u32> {
-    s.split_whitespace().flat_map(parse_u32).collect_vec()
+/// Returns the text of [s] after the [literal] it starts with.
+fn expect_literal<'a>(s: &'a str, literal: &str) -> Result<&'a str> {
+    s.strip_prefix(literal).ok_or_else(|| {
+        let width = s
+            .char_indices()
+            .nth(literal.chars().count())
+            .map_or(s.len(), |(i, _)| i);
+
+        Error::new(format!("expected `{literal}`")).at(&s[..width.max(next_char_len(s))])
+    })
 }
 
-/// Returns the result of parsing [s] as a u64.
-pub fn parse_u64(s: &str) -> Option<u64> {
-    s.parse().ok()
+/// Returns the length of the [suffix] if [s] ends with it, which is where the last placeholder ends.
+fn common_suffix_len(s: &str, suffix: &str) -> usize {
+    if s.ends_with(suffix) {
+        suffix.len()
+    } else {
+        0
+    }
 }
 
-/// Returns a new vector containing each space-delimited u64 in [s].
-pub fn parse_u64_list(s: &str) -> Vec<u64> {
-    s.split_whitespace().flat_map(parse_u64).collect_vec()
+fn next_char_len(s: &str) -> usize {
+    s.chars().next().map_or(0, char::len_utf8)
+}
+
+/// Matches a line against a template with `{}` placeholders, returning the text of each placeholder parsed as the
+/// given type, or borrowed if the type is `&str`. A single type returns its value, and several return a tuple.
+///
+/// ```ignore
+/// let (index, draws) = scan!("Game {}: {}", line => u32, &str)?;
+/// let distance = scan!("forward {}", line => i32)?;
+/// ```
+///
+/// See [scan_captures] for how the template is matched.
+#[macro_export]
+macro_rules! scan {
+    ($template:expr, $s:expr => $($types:tt)+) => {
+        $crate::scan!(@munch ($template, $s) [] $($types)+)
+    };
+
+    (@munch $args:tt [$($done:tt)*] & str $(, $($rest:tt)*)?) => {
+        $crate::scan!(@munch $args [$($done)* (str)] $($($rest)*)?)
+    };
+
+    (@munch $args:tt [$($done:tt)*] $t:ty $(, $($rest:tt)*)?) => {
+        $crate::scan!(@munch $args [$($done)* (parse $t)] $($($rest)*)?)
+    };
+
+    (@munch ($template:expr, $s:expr) [$single:tt]) => {
+        $crate::parse::scan_captures($template, $s, 1)
+            .and_then(|captures| Ok($crate::scan!(@capture captures[0], $single)))
+    };
+
+    (@munch ($template:expr, $s:expr) [$($done:tt)*]) => {
+        $crate::parse::scan_captures($template, $s, 0 $(+ $crate::scan!(@one $done))*).and_then(|captures| {
+            let mut captures = captures.into_iter();
+            Ok(($($crate::scan!(@capture captures.next().unwrap(), $done),)*))
+        })
+    };
+
+    (@capture $capture:expr, (str)) => {
+        $capture
+    };
+
+    (@capture $capture:expr, (parse $t:ty)) => {
+        $crate::parse::parse_value::<$t>($capture)?
+    };
+
+    (@one $done:tt) => {
+        1
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints_reports_an_integer_too_large_for_the_type() {
+        let text = "1 22 999 4";
+        let error = ints::<u8>(text).collect::<Result<Vec<_>>>().unwrap_err();
+        let location = error.locate(text).location().cloned().unwrap();
+
+        assert_eq!((location.column, location.width), (6, 3));
+    }
+
+    #[test]
+    fn ints_yields_each_integer() {
+        let values: Vec<i32> = ints("x=-3, y=14").collect::<Result<_>>().unwrap();
+        assert_eq!(values, vec![-3, 14]);
+
+        let values: Vec<u32> = uints("1-3").collect::<Result<_>>().unwrap();
+        assert_eq!(values, vec![1, 3]);
+    }
+
+    /// Returns the column and width of the [error] within the [text].
+    fn span(error: Error, text: &str) -> (usize, usize) {
+        let location = error.locate(text).location().cloned().unwrap();
+        (location.column, location.width)
+    }
+
+    #[test]
+    fn scans_a_single_capture() {
+        fn scan(line: &str) -> Result<i32> {
+            scan!("forward {}", line => i32)
+        }
+
+        assert_eq!(scan("forward -5").unwrap(), -5);
+        assert!(scan("forward x").is_err());
+    }
+
+    #[test]
+    fn scans_a_tuple_of_captures() {
+        fn scan(line: &str) -> Result<(u32, &str, char)> {
+            scan!("Game {}: {} ({})", line => u32, &str, char)
+        }
+
+        let line = "Game 12: 3 blue, 4 red (x)";
+        let (index, draws, mark) = scan(line).unwrap();
+
+        assert_eq!((index, draws, mark), (12, "3 blue, 4 red", 'x'));
+
+        // A `&str` capture is a slice of the line rather than a copy, so errors attached to it stay located.
+        assert_eq!(draws.as_ptr(), line[9..].as_ptr());
+    }
+
+    #[test]
+    fn locates_a_missing_literal() {
+        let text = "Game 12; 3 blue";
+        let error = scan_captures("Game {}: {}", text, 2).unwrap_err();
+
+        assert_eq!(error.message(), "expected `: `");
+        assert_eq!(span(error, text), (6, 10));
+
+        let text = "Gaem 12: 3 blue";
+        let error = scan_captures("Game {}: {}", text, 2).unwrap_err();
+
+        assert_eq!(error.message(), "expected `Game `");
+        assert_eq!(span(error, text), (1, 5));
+    }
+
+    #[test]
+    fn locates_a_missing_trailing_literal() {
+        let text = "move 3 from 1 to 2";
+        let error = scan_captures("move {} from {} to {}.", text, 3).unwrap_err();
+
+        assert_eq!(error.message(), "expected `.`");
+        assert_eq!(span(error, text), (19, 0));
+    }
+
+    #[test]
+    fn locates_an_empty_capture() {
+        let text = "x=, y=4";
+        let error = scan_captures("x={}, y={}", text, 2).unwrap_err();
+
+        assert_eq!(error.message(), "expected a value");
+        assert_eq!(span(error, text), (3, 1));
+
+        let text = "x=1, y=";
+        let error = scan_captures("x={}, y={}", text, 2).unwrap_err();
+        assert_eq!(error.message(), "expected a value");
+    }
+
+    #[test]
+    fn rejects_text_after_the_template() {
+        let text = "abc!";
+        let error = scan_captures("abc", text, 0).unwrap_err();
+
+        assert_eq!(error.message(), "unexpected `!`");
+        assert_eq!(span(error, text), (4, 1));
+        assert_eq!(scan_captures("abc", "abc", 0).ok(), Some(vec![]));
+    }
+
+    #[test]
+    #[should_panic(expected = "must have 2 placeholders")]
+    fn panics_on_a_wrong_number_of_placeholders() {
+        let _ = scan_captures("{} and {} and {}", "1 and 2 and 3", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "placeholders with nothing between them")]
+    fn panics_on_adjacent_placeholders() {
+        let _ = scan_captures("{}{}", "12", 2);
+    }
+
+    /// Returns the byte offset of the [slice] within the [text] it was taken from.
+    fn offset(slice: &str, text: &str) -> usize {
+        slice.as_ptr() as usize - text.as_ptr() as usize
+    }
+
+    #[test]
+    fn splits_paragraphs_on_blank_lines() {
+        let text = "\n\na\nb\n\n\n\nc\n\n";
+        let found: Vec<&str> = paragraphs(text).collect();
+
+        assert_eq!(found, vec!["a\nb", "c"]);
+        assert_eq!(offset(found[0], text), 2);
+        assert_eq!(offset(found[1], text), 9);
+    }
+
+    #[test]
+    fn treats_whitespace_only_lines_as_blank() {
+        let text = "a\n  \t\nb\n \nc";
+        let found: Vec<&str> = paragraphs(text).collect();
+
+        assert_eq!(found, vec!["a", "b", "c"]);
+        assert_eq!(offset(found[2], text), text.len() - 1);
+    }
+
+    #[test]
+    fn finds_no_paragraphs_in_blank_text() {
+        assert_eq!(paragraphs("").count(), 0);
+        assert_eq!(paragraphs("\n \n\n").count(), 0);
+    }
+
+    #[test]
+    fn splits_sections_after_their_header() {
+        let text = "seeds: 1 2\n\nseed-to-soil map:\n50 98 2\n52 50 48\n";
+        let found: Vec<Section> = sections(text).collect();
+
+        assert_eq!(found.len(), 2);
+
+        assert_eq!(found[0].header, "seeds: 1 2");
+        assert_eq!(found[0].body, "");
+        assert_eq!(offset(found[0].body, text), 10);
+
+        assert_eq!(found[1].title(), "seed-to-soil map");
+        assert_eq!(found[1].body, "50 98 2\n52 50 48");
+        assert_eq!(offset(found[1].body, text), 30);
+    }
+
+    #[test]
+    fn locates_errors_in_a_section_body() {
+        let text = "a:\n1\n\nb:\n2\nx";
+        let body = sections(text).nth(1).unwrap().body;
+        let token = body.lines().nth(1).unwrap();
+
+        let location = Error::new("bad")
+            .at(token)
+            .locate(text)
+            .location()
+            .cloned()
+            .unwrap();
+        assert_eq!((location.line, location.column), (6, 1));
+    }
+
+    #[test]
+    fn trims_the_title() {
+        assert_eq!(Section::new("Player 1:  ").title(), "Player 1");
+        assert_eq!(Section::new("no colon").title(), "no colon");
+    }
 }
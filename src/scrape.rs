@@ -0,0 +1,256 @@
+use crate::answers::Answers;
+use crate::examples::Example;
+use crate::Answer;
+
+/// Returns the examples found in the HTML [page] of a puzzle description.
+///
+/// Each part of the puzzle is described in its own `<article>`. The first `<pre><code>` block of the first part is
+/// taken as its example input, and the last emphasized code (`<code><em>..</em></code>`) outside any block as its
+/// expected answer. The second part, once unlocked, adds its answer to the same example, unless it has a block of its
+/// own, in which case that block becomes a second example.
+///
+/// These are heuristics, since a description may show several blocks or emphasize other values, so the examples
+/// should be checked by eye.
+pub fn scrape_examples(page: &str) -> Vec<Example> {
+    let articles = tag_contents(page, "<article", "</article>");
+
+    let mut examples: Vec<Example> = vec![];
+
+    for (index, article) in articles.iter().take(2).enumerate() {
+        let blocks = tag_contents(article, "<pre><code>", "</code></pre>");
+
+        let answer = last_emphasized_code(&remove_blocks(article)).map(|s| answer_from_text(&s));
+
+        let input = blocks.first().map(|block| {
+            let text = decode_entities(&strip_tags(block));
+            text.strip_suffix('\n').unwrap_or(&text).to_string()
+        });
+
+        if let Some(input) = input {
+            examples.push(Example {
+                name: (examples.len() + 1).to_string(),
+                input,
+                answers: Answers::default(),
+            });
+        }
+
+        let Some(example) = examples.last_mut() else {
+            continue;
+        };
+
+        if index == 0 {
+            example.answers.part_one = answer;
+        } else {
+            example.answers.part_two = answer;
+        }
+    }
+
+    examples
+}
+
+/// Returns the text between each [open] and [close] tag in the [html], where [open] may omit the end of the tag so
+/// that it matches any attributes.
+fn tag_contents<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut contents = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(open) {
+        let after = &rest[start + open.len()..];
+
+        // An open tag without its closing bracket, such as `<article`, is completed by the next `>`.
+
+        let after = if open.ends_with('>') {
+            after
+        } else {
+            match after.find('>') {
+                Some(end) => &after[end + 1..],
+                None => break,
+            }
+        };
+
+        let Some(end) = after.find(close) else {
+            break;
+        };
+
+        contents.push(&after[..end]);
+        rest = &after[end + close.len()..];
+    }
+
+    contents
+}
+
+/// Returns the [html] without its `<pre>` blocks.
+fn remove_blocks(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<pre>") {
+        text.push_str(&rest[..start]);
+
+        match rest[start..].find("</pre>") {
+            Some(end) => rest = &rest[start + end + "</pre>".len()..],
+            None => rest = "",
+        }
+    }
+
+    text.push_str(rest);
+    text
+}
+
+/// Returns the text of the last emphasized code in the [html], written as either `<code><em>` or `<em><code>`.
+fn last_emphasized_code(html: &str) -> Option<String> {
+    let code_em = tag_contents(html, "<code><em>", "</em></code>");
+    let em_code = tag_contents(html, "<em><code>", "</code></em>");
+
+    // Both forms can appear in one description, so compare where the last of each was found.
+
+    let last = |matches: &[&str]| matches.last().map(|s| (s.as_ptr() as usize, s.to_string()));
+
+    [last(&code_em), last(&em_code)]
+        .into_iter()
+        .flatten()
+        .max_by_key(|(position, _)| *position)
+        .map(|(_, s)| decode_entities(&strip_tags(&s)))
+}
+
+fn answer_from_text(text: &str) -> Answer {
+    match text.trim().parse::<i128>() {
+        Ok(n) => Answer::Integer(n),
+        Err(_) => Answer::from_text(text),
+    }
+}
+
+/// Returns the [html] with every tag removed.
+pub(crate) fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+/// Returns the [text] with the character entities used in puzzle descriptions decoded.
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples;
+
+    const SHARED_EXAMPLE: &str = include_str!("../tests/fixtures/shared_example.html");
+    const SEPARATE_EXAMPLES: &str = include_str!("../tests/fixtures/separate_examples.html");
+
+    #[test]
+    fn adds_the_second_answer_to_a_shared_example() {
+        let examples = scrape_examples(SHARED_EXAMPLE);
+
+        assert_eq!(examples.len(), 1);
+
+        let example = &examples[0];
+        assert_eq!(example.name, "1");
+        assert_eq!(example.answers.part_one, Some(Answer::Integer(26397)));
+        assert_eq!(example.answers.part_two, Some(Answer::Integer(288957)));
+    }
+
+    #[test]
+    fn decodes_entities_in_blocks() {
+        let examples = scrape_examples(SHARED_EXAMPLE);
+
+        assert_eq!(
+            examples[0].input,
+            "[({(<(())[]>[[{[]{<()<>>\n\
+             [(()[<>])]({[<{<<[]>>(\n\
+             {([(<{}[<>[]}>{[]{[(<()>\n\
+             (((({<>}<{<{<>}{[]{[]{}"
+        );
+    }
+
+    #[test]
+    fn makes_a_second_example_of_a_second_block() {
+        let examples = scrape_examples(SEPARATE_EXAMPLES);
+
+        assert_eq!(examples.len(), 2);
+
+        assert_eq!(examples[0].input, "1abc2\npqr3stu8vwx");
+        assert_eq!(examples[0].answers.part_one, Some(Answer::Integer(142)));
+        assert_eq!(examples[0].answers.part_two, None);
+
+        assert_eq!(examples[1].name, "2");
+        assert_eq!(examples[1].input, "two1nine\neightwothree");
+        assert_eq!(examples[1].answers.part_one, None);
+        assert_eq!(examples[1].answers.part_two, Some(Answer::Integer(281)));
+    }
+
+    #[test]
+    fn scrapes_only_the_first_part_before_it_is_solved() {
+        let locked = SEPARATE_EXAMPLES.split("<article class=\"day-desc\"><h2 id=\"part2\">");
+        let examples = scrape_examples(locked.into_iter().next().unwrap());
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].answers.part_two, None);
+        assert!(scrape_examples("<html><body>no puzzle</body></html>").is_empty());
+    }
+
+    #[test]
+    fn finds_the_last_emphasized_code_in_either_form() {
+        let code_em_last =
+            "<code><em>1</em></code> <em><code>2</code></em> <code><em>3</em></code>";
+        let em_code_last = "<code><em>1</em></code> <em><code>2</code></em>";
+
+        assert_eq!(last_emphasized_code(code_em_last).as_deref(), Some("3"));
+        assert_eq!(last_emphasized_code(em_code_last).as_deref(), Some("2"));
+        assert_eq!(
+            last_emphasized_code("<em><code>a &amp; b</code></em>").as_deref(),
+            Some("a & b")
+        );
+        assert_eq!(last_emphasized_code("<code>1</code> <em>2</em>"), None);
+    }
+
+    #[test]
+    fn removes_blocks_and_finds_tag_contents() {
+        assert_eq!(remove_blocks("a<pre>b</pre>c<pre>d"), "ac");
+        assert_eq!(
+            tag_contents("<p class=\"x\">a</p><p>b</p><p>c", "<p", "</p>"),
+            vec!["a", "b"]
+        );
+        assert_eq!(
+            decode_entities("&amp;lt; &lt;&gt; &quot;&#39;"),
+            "&lt; <> \"'"
+        );
+    }
+
+    #[test]
+    fn round_trips_scraped_examples_through_toml() {
+        let mut scraped = scrape_examples(SEPARATE_EXAMPLES);
+        scraped.extend(scrape_examples(SHARED_EXAMPLE));
+        scraped[2].name = String::from("syntax");
+        scraped[2].answers.part_two = Some(Answer::from_text("ABC"));
+
+        let text = examples::to_toml(&scraped).unwrap();
+
+        assert_eq!(examples::parse(&text).unwrap(), scraped);
+    }
+
+    #[test]
+    fn refuses_inputs_that_cannot_be_written_as_literal_strings() {
+        let mut examples = scrape_examples(SEPARATE_EXAMPLES);
+        examples[0].input.push_str("\n'''");
+
+        assert!(examples::to_toml(&examples).is_err());
+    }
+}
@@ -0,0 +1,289 @@
+use std::any::Any;
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+
+/// A puzzle solution, registered in each `src/bin` file by the [aoc](crate::aoc) macro.
+///
+/// Solving is split into a shared parse step and the two parts, so that each can be invoked (and timed) separately.
+pub trait Solution {
+    /// Returns the year of the puzzle.
+    fn year(&self) -> u32;
+
+    /// Returns the day of the puzzle.
+    fn day(&self) -> u32;
+
+    /// Returns the title of the puzzle.
+    fn title(&self) -> &str;
+
+    /// Returns the result of parsing the puzzle [text] into the input shared by both parts.
+    fn parse(&self, text: &str) -> Result<Input>;
+
+    /// Returns true if the solution has a parse step of its own, rather than each part taking the puzzle text.
+    fn has_parse_step(&self) -> bool;
+
+    /// Returns the answer to part one for the parsed [input].
+    fn part_one(&self, input: &Input) -> Result<Answer>;
+
+    /// Returns the answer to part two for the parsed [input].
+    fn part_two(&self, input: &Input) -> Result<Answer>;
+
+    /// Returns the answer to the specified [part] for the parsed [input].
+    fn solve(&self, input: &Input, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }
+    }
+
+    /// Returns the `YYYY_DD` name of the puzzle.
+    fn name(&self) -> String {
+        format!("{}_{:02}", self.year(), self.day())
+    }
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "part one"),
+            Part::Two => write!(f, "part two"),
+        }
+    }
+}
+
+/// A puzzle input produced by the parse step of a [Solution], and only usable by the same solution.
+pub struct Input {
+    text: String,
+    value: Box<dyn Any>,
+}
+
+impl Input {
+    /// Returns the text the input was parsed from.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// A value returned by a parse function, either directly or as the Ok value of a [Result].
+pub trait IntoResult<T> {
+    fn into_result(self) -> Result<T>;
+}
+
+impl<T> IntoResult<T> for T {
+    fn into_result(self) -> Result<T> {
+        Ok(self)
+    }
+}
+
+impl<T> IntoResult<T> for Result<T> {
+    fn into_result(self) -> Result<T> {
+        self
+    }
+}
+
+/// A value returned by a part function, either a value convertible into an [Answer] or a [Result] of one.
+///
+/// The [M] parameter is one of the marker types [Infallible] or [Fallible], and is always inferred.
+pub trait IntoAnswer<M> {
+    fn into_answer(self) -> Result<Answer>;
+}
+
+/// Marker for part functions that return their answer directly.
+pub struct Infallible;
+
+/// Marker for part functions that return a [Result] of their answer.
+pub struct Fallible;
+
+impl<T: Into<Answer>> IntoAnswer<Infallible> for T {
+    fn into_answer(self) -> Result<Answer> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>> IntoAnswer<Fallible> for Result<T> {
+    fn into_answer(self) -> Result<Answer> {
+        self.map(Into::into)
+    }
+}
+
+type ParseFn = Box<dyn Fn(&str) -> Result<Box<dyn Any>>>;
+type PartFn = Box<dyn Fn(&Input) -> Result<Answer>>;
+
+/// A [Solution] built from the functions of a single `src/bin` file.
+pub struct Day {
+    year: u32,
+    day: u32,
+    title: &'static str,
+    /// The parse step, or None if the parts take the puzzle text.
+    parse: Option<ParseFn>,
+    part_one: PartFn,
+    part_two: PartFn,
+}
+
+impl Day {
+    /// Creates a new Day for the puzzle identified by the `YYYY_DD` stem of the source [file], whose parts take the
+    /// puzzle text.
+    pub fn from_text<A, B, MA, MB>(
+        file: &str,
+        title: &'static str,
+        part_one: fn(&str) -> A,
+        part_two: fn(&str) -> B,
+    ) -> Self
+    where
+        A: IntoAnswer<MA> + 'static,
+        B: IntoAnswer<MB> + 'static,
+    {
+        Day::build(
+            file,
+            title,
+            None,
+            Box::new(move |input| part_one(input.text()).into_answer()),
+            Box::new(move |input| part_two(input.text()).into_answer()),
+        )
+    }
+
+    /// Creates a new Day for the puzzle identified by the `YYYY_DD` stem of the source [file], whose parts take
+    /// the value returned by the [parse] function.
+    pub fn new<I, P, A, B, MA, MB>(
+        file: &str,
+        title: &'static str,
+        parse: fn(&str) -> P,
+        part_one: fn(&I) -> A,
+        part_two: fn(&I) -> B,
+    ) -> Self
+    where
+        I: 'static,
+        P: IntoResult<I> + 'static,
+        A: IntoAnswer<MA> + 'static,
+        B: IntoAnswer<MB> + 'static,
+    {
+        fn downcast<I: 'static>(input: &Input) -> &I {
+            input
+                .value
+                .downcast_ref()
+                .expect("input was parsed by a different solution")
+        }
+
+        Day::build(
+            file,
+            title,
+            Some(Box::new(move |text| {
+                Ok(Box::new(parse(text).into_result()?))
+            })),
+            Box::new(move |input| part_one(downcast(input)).into_answer()),
+            Box::new(move |input| part_two(downcast(input)).into_answer()),
+        )
+    }
+
+    fn build(
+        file: &str,
+        title: &'static str,
+        parse: Option<ParseFn>,
+        part_one: PartFn,
+        part_two: PartFn,
+    ) -> Self {
+        let stem = Path::new(file).file_stem().and_then(|s| s.to_str());
+        let (year, day) = stem
+            .and_then(parse_name)
+            .expect("solution file must be named YYYY_DD.rs");
+
+        Day {
+            year,
+            day,
+            title,
+            parse,
+            part_one,
+            part_two,
+        }
+    }
+}
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        self.year
+    }
+
+    fn day(&self) -> u32 {
+        self.day
+    }
+
+    fn title(&self) -> &str {
+        self.title
+    }
+
+    fn parse(&self, text: &str) -> Result<Input> {
+        let value = match &self.parse {
+            Some(parse) => parse(text).map_err(|e: Error| e.locate(text))?,
+            None => Box::new(()),
+        };
+
+        Ok(Input {
+            text: text.to_string(),
+            value,
+        })
+    }
+
+    fn has_parse_step(&self) -> bool {
+        self.parse.is_some()
+    }
+
+    fn part_one(&self, input: &Input) -> Result<Answer> {
+        (self.part_one)(input).map_err(|e| e.locate(input.text()))
+    }
+
+    fn part_two(&self, input: &Input) -> Result<Answer> {
+        (self.part_two)(input).map_err(|e| e.locate(input.text()))
+    }
+}
+
+/// Returns the year and day of a `YYYY_DD` puzzle [name], or None if it is malformed.
+pub fn parse_name(name: &str) -> Option<(u32, u32)> {
+    let (year, day) = name.split_once('_')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_input(text: &str) -> Result<u32> {
+        text.parse()
+            .map_err(|_| Error::new("expected a number").at(text))
+    }
+
+    #[test]
+    fn only_parses_with_a_parse_step() {
+        let from_text = Day::from_text("2021_01.rs", "", str::len, str::len);
+        let parsed = Day::new(
+            "2021_01.rs",
+            "",
+            parse_input,
+            |&n: &u32| n,
+            |&n: &u32| n * 2,
+        );
+
+        assert!(!from_text.has_parse_step());
+        assert!(parsed.has_parse_step());
+
+        let input = from_text.parse("12").unwrap();
+        assert_eq!(from_text.part_one(&input).unwrap(), Answer::from(2usize));
+
+        let input = parsed.parse("12").unwrap();
+        assert_eq!(parsed.part_two(&input).unwrap(), Answer::from(24u32));
+        assert!(parsed.parse("twelve").is_err());
+    }
+}
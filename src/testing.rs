@@ -0,0 +1,63 @@
+use crate::answers::{Answers, Check};
+use crate::examples;
+use crate::input::InputSource;
+use crate::{Part, Solution};
+
+/// Asserts that the [solution] produces the expected answers to each of its examples.
+pub fn check_examples(solution: &dyn Solution) {
+    let examples = examples::load(&solution.name()).unwrap_or_else(|e| panic!("{e}"));
+
+    let mut failures = vec![];
+
+    for example in &examples {
+        let input = solution
+            .parse(&example.input)
+            .unwrap_or_else(|e| panic!("example `{}`: {}", example.name, e.report()));
+
+        for part in Part::ALL {
+            let Some(expected) = example.answers.get(part) else {
+                continue;
+            };
+
+            let actual = solution
+                .solve(&input, part)
+                .unwrap_or_else(|e| panic!("example `{}` {part}: {}", example.name, e.report()));
+
+            if let Check::Fail { expected } = Check::new(Some(expected), &actual) {
+                failures.push(format!(
+                    "example `{}` {part}: expected {expected}, got {actual}",
+                    example.name
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Asserts that the [solution] produces the recorded answer to the specified [part] of its input.
+/// Does nothing if the answer has not been recorded.
+pub fn check_answer(solution: &dyn Solution, part: Part) {
+    let name = solution.name();
+
+    let answers = Answers::load(&name).unwrap_or_else(|e| panic!("{e}"));
+
+    let Some(expected) = answers.get(part) else {
+        return;
+    };
+
+    let (text, _) = InputSource::Default
+        .read_checked(&name)
+        .unwrap_or_else(|e| panic!("{e}"));
+    let input = solution
+        .parse(&text)
+        .unwrap_or_else(|e| panic!("{name}: {}", e.report()));
+
+    let actual = solution
+        .solve(&input, part)
+        .unwrap_or_else(|e| panic!("{name} {part}: {}", e.report()));
+
+    if let Check::Fail { expected } = Check::new(Some(expected), &actual) {
+        panic!("{name} {part}: expected {expected}, got {actual}");
+    }
+}
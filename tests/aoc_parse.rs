@@ -0,0 +1,108 @@
+use aoc::{AocParse, Result};
+
+#[derive(Debug, PartialEq, AocParse)]
+#[aoc(fmt = "{ax},{ay} -> {bx},{by}")]
+struct Line {
+    ax: usize,
+    ay: usize,
+    bx: usize,
+    by: usize,
+}
+
+#[derive(Debug, PartialEq, AocParse)]
+#[aoc(fmt = "{0} x {1}")]
+struct Size(u32, u32);
+
+#[derive(Debug, PartialEq, AocParse)]
+enum Command {
+    #[aoc(fmt = "forward {0}")]
+    Forward(i32),
+    #[aoc(fmt = "turn {direction} by {degrees}")]
+    Turn { direction: String, degrees: u32 },
+    #[aoc(fmt = "stop")]
+    Stop,
+}
+
+#[derive(Debug, PartialEq, AocParse)]
+#[aoc(fmt = "{size} at {index}")]
+struct Placed {
+    size: Size,
+    index: usize,
+}
+
+#[test]
+fn parses_a_struct() {
+    let line: Line = "0,9 -> 5,9".parse().unwrap();
+
+    assert_eq!(
+        line,
+        Line {
+            ax: 0,
+            ay: 9,
+            bx: 5,
+            by: 9
+        }
+    );
+}
+
+#[test]
+fn parses_a_tuple_struct() {
+    assert_eq!("3 x 4".parse::<Size>().unwrap(), Size(3, 4));
+}
+
+#[test]
+fn parses_each_variant_of_an_enum() {
+    let commands: Result<Vec<Command>> = ["forward 5", "turn left by 90", "stop"]
+        .iter()
+        .map(|s| s.parse())
+        .collect();
+
+    assert_eq!(
+        commands.unwrap(),
+        vec![
+            Command::Forward(5),
+            Command::Turn {
+                direction: String::from("left"),
+                degrees: 90
+            },
+            Command::Stop,
+        ]
+    );
+}
+
+#[test]
+fn locates_an_invalid_field() {
+    let text = "0,9 -> x,9";
+    let error = text.parse::<Line>().unwrap_err().locate(text);
+    let location = error.location().unwrap();
+
+    assert_eq!((location.column, location.width), (8, 1));
+}
+
+#[test]
+fn reports_the_variant_that_matched_a_prefix() {
+    let error = "forward x".parse::<Command>().unwrap_err();
+    assert!(error.message().contains("invalid value `x`"), "{error}");
+
+    let error = "jump".parse::<Command>().unwrap_err();
+    assert!(error.message().starts_with("expected one of"), "{error}");
+}
+
+#[test]
+fn locates_an_invalid_field_of_a_nested_type() {
+    let text = "3 x 4 at 1";
+    assert_eq!(
+        text.parse::<Placed>().unwrap(),
+        Placed {
+            size: Size(3, 4),
+            index: 1
+        }
+    );
+
+    let text = "3 x y at 1";
+    let error = text.parse::<Placed>().unwrap_err().locate(text);
+    let location = error.location().unwrap();
+
+    assert!(error.message().contains("invalid value `y`"), "{error}");
+    assert_eq!((location.column, location.width), (1, 5));
+}